@@ -0,0 +1,774 @@
+use base64::Engine;
+use image::ImageFormat;
+use pulldown_cmark::{html, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Display, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syntect::highlighting::ThemeSet;
+use syntect::html::{css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+// The datafiles are checked against the same definitions the site deserializes them with
+#[allow(dead_code)]
+#[path = "src/data"]
+mod data {
+    pub mod date;
+    pub mod lines;
+    pub mod types;
+}
+
+// Posts are rendered with the same rules as markdown rendered at runtime
+#[path = "src/markdown"]
+mod markdown {
+    pub mod sanitize;
+}
+
+use data::date::{Date, DateEnd, Dates, YearMonth};
+use data::lines::value_lines;
+use data::types::{
+    hex_rgb, slugify, About, AboutSection, Breakpoints, Education, Experience, FrontMatter,
+    LegalSection, Palette, Post, Privacy, Profile, Project, Radii, Resume, SkillCategory,
+    SocialLink, Spacing, Terms, Theme,
+};
+
+const FLAGS: [&str; 2] = ["ENABLE_CONTACT_FORM", "SHOW_LEGAL"];
+
+const DATAFILE_DIR: &str = "src/datafile";
+/// Inside [`DATAFILE_DIR`].
+const POST_DIR: &str = "posts";
+const WORDS_PER_MINUTE: usize = 200;
+/// Highlighting theme of code blocks for each value of `data-theme`.
+const CODE_THEMES: [(&str, &str); 2] = [("dark", "base16-ocean.dark"), ("light", "InspiredGitHub")];
+const CODE_CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+const IMAGE_DIR: &str = "assets/images";
+const PLACEHOLDER_WIDTH: u32 = 16;
+
+fn main() {
+    check_config();
+    generate_datafiles(check_datafiles());
+    generate_highlight_css();
+    generate_image_manifest();
+}
+
+fn check_config() {
+    println!("cargo:rerun-if-env-changed=API_SERVER");
+    println!("cargo:rerun-if-env-changed=CONTENT_URL");
+    for flag in FLAGS {
+        println!("cargo:rerun-if-env-changed={flag}");
+    }
+
+    let api_server = env::var("API_SERVER").unwrap_or_default();
+    if !api_server.is_empty()
+        && !api_server.starts_with("https://")
+        && !api_server.starts_with("http://")
+    {
+        panic!("API_SERVER must be an http(s) url, got `{api_server}`");
+    }
+
+    let content_url = env::var("CONTENT_URL").unwrap_or_default();
+    if !content_url.is_empty()
+        && !content_url.starts_with("https://")
+        && !content_url.starts_with("http://")
+        && !content_url.starts_with('/')
+    {
+        panic!("CONTENT_URL must be an http(s) url or a path starting with `/`, got `{content_url}`");
+    }
+
+    for flag in FLAGS {
+        let value = env::var(flag).unwrap_or_default();
+        if !matches!(value.as_str(), "" | "0" | "1" | "false" | "true") {
+            panic!("{flag} must be `true`, `false`, `1` or `0`, got `{value}`");
+        }
+    }
+
+    let enabled = |flag: &str| matches!(env::var(flag).as_deref(), Ok("1" | "true"));
+    let features = [
+        ("ENABLE_CONTACT_FORM", "contact-form", "CARGO_FEATURE_CONTACT_FORM"),
+        ("SHOW_LEGAL", "legal-pages", "CARGO_FEATURE_LEGAL_PAGES"),
+    ];
+    for (flag, feature, cargo_env) in features {
+        if enabled(flag) && env::var_os(cargo_env).is_none() {
+            println!("cargo:warning={flag} has no effect because the `{feature}` feature is disabled");
+        }
+    }
+
+    // The runtime config.json may still provide the url, so this is not fatal
+    if enabled("ENABLE_CONTACT_FORM")
+        && env::var_os("CARGO_FEATURE_CONTACT_FORM").is_some()
+        && api_server.is_empty()
+    {
+        println!("cargo:warning=ENABLE_CONTACT_FORM is set without API_SERVER; the site will show a configuration error unless config.json provides `api_url`");
+    }
+}
+
+/// Collects every problem in one datafile so a single build reports all of them.
+struct Datafile<'a> {
+    path: String,
+    source: String,
+    lines: HashMap<String, usize>,
+    errors: &'a mut Vec<String>,
+}
+
+impl<'a> Datafile<'a> {
+    fn open(name: &str, errors: &'a mut Vec<String>) -> Self {
+        let path = format!("{DATAFILE_DIR}/{name}");
+        let source =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("Failed to read {path}: {err}"));
+        let lines = value_lines(&source);
+        Self { path, source, lines, errors }
+    }
+
+    /// A markdown file whose front matter is checked like a datafile, with errors reported at the
+    /// line of each key.
+    fn open_post(name: &str, errors: &'a mut Vec<String>) -> Self {
+        let path = format!("{DATAFILE_DIR}/{POST_DIR}/{name}");
+        let source =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("Failed to read {path}: {err}"));
+        let lines = source
+            .lines()
+            .enumerate()
+            .skip(1)
+            .take_while(|(_, line)| line.trim_end() != "---")
+            .filter_map(|(index, line)| {
+                let (key, _) = line.split_once(':')?;
+                let top_level = !key.starts_with(char::is_whitespace) && !key.starts_with('-');
+                top_level.then(|| (key.trim().to_string(), index + 1))
+            })
+            .collect();
+        Self { path, source, lines, errors }
+    }
+
+    fn error(&mut self, field: &str, message: impl Display) {
+        // A field that is missing entirely is reported at the object that should contain it
+        let line = field
+            .char_indices()
+            .rev()
+            .filter(|(_, c)| matches!(c, '.' | '['))
+            .map(|(index, _)| &field[..index])
+            .chain([""])
+            .find_map(|path| self.lines.get(path));
+        let line = self.lines.get(field).or(line).copied().unwrap_or(1);
+        self.push(line, field, message);
+    }
+
+    fn push(&mut self, line: usize, field: &str, message: impl Display) {
+        let error = match field {
+            "" | "." => format!("{}:{line}: {message}", self.path),
+            field => format!("{}:{line}: {field}: {message}", self.path),
+        };
+        self.errors.push(error);
+    }
+
+    fn parse<T: DeserializeOwned>(&mut self) -> Option<T> {
+        let deserializer = &mut serde_json::Deserializer::from_str(&self.source);
+        match serde_path_to_error::deserialize(deserializer) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                let line = err.inner().line();
+                let field = err.path().to_string();
+                // The location is already part of the report
+                let message = err.into_inner().to_string();
+                let message = message
+                    .rsplit_once(" at line ")
+                    .map_or(message.as_str(), |(message, _)| message);
+                self.push(line, &field, message);
+                None
+            }
+        }
+    }
+
+    /// The front matter between the leading `---` lines and the markdown after it.
+    fn parse_front_matter<T: DeserializeOwned>(&mut self) -> Option<(T, String)> {
+        let source = self.source.replace("\r\n", "\n");
+        let split = source
+            .strip_prefix("---\n")
+            .and_then(|rest| rest.split_once("\n---\n").or_else(|| rest.split_once("\n---")));
+        let Some((front_matter, body)) = split else {
+            self.push(1, "", "must start with front matter between two `---` lines");
+            return None;
+        };
+
+        let deserializer = serde_yaml::Deserializer::from_str(front_matter);
+        match serde_path_to_error::deserialize(deserializer) {
+            Ok(value) => Some((value, body.to_string())),
+            Err(err) => {
+                let field = err.path().to_string();
+                let err = err.into_inner();
+                let message = err.to_string();
+                // serde_yaml already leads with the field
+                let message = message
+                    .rsplit_once(" at line ")
+                    .map_or(message.as_str(), |(message, _)| message);
+                let message = message
+                    .strip_prefix(&format!("{field}: "))
+                    .unwrap_or(message);
+                match err.location() {
+                    // Syntax errors have no field, the opening `---` comes before the location
+                    Some(location) if matches!(field.as_str(), "" | ".") => {
+                        self.push(location.line() + 1, "", message)
+                    }
+                    _ => self.error(&field, message),
+                }
+                None
+            }
+        }
+    }
+
+    fn non_empty(&mut self, field: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.error(field, "must not be empty");
+        }
+    }
+
+    fn url(&mut self, field: &str, value: &Option<String>) {
+        let Some(value) = value else {
+            return;
+        };
+        let valid = url::Url::parse(value)
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some());
+        if !valid {
+            self.error(field, format!("`{value}` is not an http(s) url"));
+        }
+    }
+
+    /// An image is either a full url or a path under [`IMAGE_DIR`], the way the site resolves it.
+    fn image(&mut self, field: &str, value: &Option<String>) {
+        match value {
+            Some(src) if src.contains("://") => self.url(field, value),
+            Some(src) if !Path::new(IMAGE_DIR).join(src).is_file() => {
+                self.error(field, format!("`{src}` is not an image in {IMAGE_DIR}"));
+            }
+            _ => {}
+        }
+    }
+
+    fn dates(&mut self, field: &str, dates: &Dates) {
+        if let (Some(start), Some(DateEnd::On(end))) = (dates.start, dates.end) {
+            let before = end.year < start.year
+                || (end.year == start.year
+                    && end.month.zip(start.month).is_some_and(|(end, start)| end < start));
+            if before {
+                let message = format!("`{end}` is before the start `{start}`");
+                self.error(&format!("{field}.end"), message);
+            }
+        } else if dates.start.is_none() && dates.end.is_some() {
+            self.error(&format!("{field}.start"), "is required when there is an end");
+        }
+    }
+
+    fn legal_sections(&mut self, title: &str, effective_date: &str, sections: &[LegalSection]) {
+        self.non_empty("title", title);
+        self.non_empty("effective_date", effective_date);
+        for (index, section) in sections.iter().enumerate() {
+            self.non_empty(&format!("sections[{index}].title"), &section.title);
+            self.non_empty(&format!("sections[{index}].content"), &section.content);
+        }
+    }
+}
+
+/// Every datafile, checked and ready to be written out as Rust.
+struct Datafiles {
+    profile: Profile,
+    resume: Resume,
+    projects: Vec<Project>,
+    about: About,
+    terms: Terms,
+    privacy: Privacy,
+    theme: Theme,
+    posts: Vec<Post>,
+}
+
+/// Fails the build on datafiles that would otherwise only panic in the visitor's browser, and on
+/// content that parses but is wrong, like a malformed url or two projects with the same page.
+fn check_datafiles() -> Datafiles {
+    println!("cargo:rerun-if-changed={DATAFILE_DIR}");
+    println!("cargo:rerun-if-changed=src/data/date.rs");
+    println!("cargo:rerun-if-changed=src/data/lines.rs");
+    println!("cargo:rerun-if-changed=src/data/types.rs");
+    println!("cargo:rerun-if-changed=src/markdown/sanitize.rs");
+
+    let mut errors = Vec::new();
+
+    let mut file = Datafile::open("profile.json", &mut errors);
+    let profile = file.parse::<Profile>();
+    if let Some(profile) = &profile {
+        file.non_empty("name", &profile.name);
+        file.non_empty("title", &profile.title);
+        if profile.email.as_ref().is_some_and(|email| !email.contains('@')) {
+            file.error("email", "is not an email address");
+        }
+        file.url("linkedin", &profile.linkedin);
+        file.url("github", &profile.github);
+        file.url("website", &profile.website);
+        file.image("avatar", &profile.avatar);
+        for (index, link) in profile.social.iter().enumerate() {
+            file.non_empty(&format!("social[{index}].network"), &link.network);
+            file.url(&format!("social[{index}].url"), &Some(link.url.clone()));
+        }
+    }
+
+    let mut file = Datafile::open("resume.json", &mut errors);
+    let resume = file.parse::<Resume>();
+    if let Some(resume) = &resume {
+        for (index, experience) in resume.experience.iter().enumerate() {
+            let field = format!("experience[{index}]");
+            file.non_empty(&format!("{field}.position"), &experience.position);
+            file.non_empty(&format!("{field}.company"), &experience.company);
+            file.dates(&field, &experience.dates);
+        }
+        for (index, education) in resume.education.iter().enumerate() {
+            let field = format!("education[{index}]");
+            file.non_empty(&format!("{field}.degree"), &education.degree);
+            file.non_empty(&format!("{field}.school"), &education.school);
+            file.dates(&field, &education.dates);
+        }
+        for (index, category) in resume.skills.iter().enumerate() {
+            file.non_empty(&format!("skills[{index}].name"), &category.name);
+            for (item_index, item) in category.items.iter().enumerate() {
+                file.non_empty(&format!("skills[{index}].items[{item_index}]"), item);
+            }
+        }
+    }
+
+    let mut file = Datafile::open("projects.json", &mut errors);
+    let projects = file.parse::<Vec<Project>>();
+    if let Some(projects) = &projects {
+        let mut slugs = HashMap::new();
+        for (index, project) in projects.iter().enumerate() {
+            let field = format!("[{index}]");
+            file.non_empty(&format!("{field}.name"), &project.name);
+            file.url(&format!("{field}.website"), &project.website);
+            file.url(&format!("{field}.github"), &project.github);
+            file.image(&format!("{field}.image"), &project.image);
+            for (screenshot_index, screenshot) in project.screenshots.iter().enumerate() {
+                let screenshot = Some(screenshot.clone());
+                file.image(&format!("{field}.screenshots[{screenshot_index}]"), &screenshot);
+            }
+            file.dates(&field, &project.dates);
+            let slug = project.slug();
+            if slug.is_empty() {
+                // Without a slug the detail link would lead back to the list of projects; an
+                // empty name is already reported above
+                if !project.name.trim().is_empty() {
+                    file.error(
+                        &format!("{field}.name"),
+                        format!("`{}` has no letters or digits", project.name),
+                    );
+                }
+            } else if let Some(first) = slugs.insert(slug, index) {
+                // Projects with the same slug would share one detail page
+                file.error(
+                    &format!("{field}.name"),
+                    format!("`{}` has the same page as projects[{first}]", project.name),
+                );
+            }
+        }
+    }
+
+    let mut file = Datafile::open("about.json", &mut errors);
+    let about = file.parse::<About>();
+    if let Some(about) = &about {
+        file.non_empty("intro", &about.intro);
+        for (index, section) in about.sections.iter().enumerate() {
+            file.non_empty(&format!("sections[{index}].title"), &section.title);
+        }
+    }
+
+    let mut file = Datafile::open("terms.json", &mut errors);
+    let terms = file.parse::<Terms>();
+    if let Some(terms) = &terms {
+        file.legal_sections(&terms.title, &terms.effective_date, &terms.sections);
+    }
+
+    let mut file = Datafile::open("privacy.json", &mut errors);
+    let privacy = file.parse::<Privacy>();
+    if let Some(privacy) = &privacy {
+        file.legal_sections(&privacy.title, &privacy.effective_date, &privacy.sections);
+    }
+
+    let mut file = Datafile::open("theme.json", &mut errors);
+    let theme = file.parse::<Theme>();
+    if let Some(theme) = &theme {
+        for (name, palette) in [("dark", &theme.dark), ("light", &theme.light)] {
+            file.non_empty(&format!("{name}.background"), &palette.background);
+            for (color, value) in palette.colors() {
+                if hex_rgb(value).is_none() {
+                    let field = format!("{name}.{}", color.replace('-', "_"));
+                    file.error(&field, format!("`{value}` is not a #rrggbb color"));
+                }
+            }
+        }
+        let spacing = &theme.spacing;
+        let radii = &theme.radii;
+        let lengths = [
+            ("spacing.xs", &spacing.xs),
+            ("spacing.sm", &spacing.sm),
+            ("spacing.md", &spacing.md),
+            ("spacing.lg", &spacing.lg),
+            ("spacing.xl", &spacing.xl),
+            ("radii.sm", &radii.sm),
+            ("radii.md", &radii.md),
+            ("radii.lg", &radii.lg),
+            ("radii.pill", &radii.pill),
+        ];
+        for (field, value) in lengths {
+            file.non_empty(field, value);
+        }
+        if theme.breakpoints.mobile == 0 {
+            file.error("breakpoints.mobile", "must be a width in pixels");
+        }
+    }
+
+    let syntaxes = SyntaxSet::load_defaults_newlines();
+    let mut names = fs::read_dir(Path::new(DATAFILE_DIR).join(POST_DIR))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| name.ends_with(".md"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort();
+    let mut posts = Some(Vec::new());
+    for name in names {
+        let mut file = Datafile::open_post(&name, &mut errors);
+        let slug = name.trim_end_matches(".md").to_string();
+        if slugify(&slug) != slug {
+            let message = format!("the file name must be a slug like `{}.md`", slugify(&slug));
+            file.push(1, "", message);
+        }
+        let Some((front_matter, body)) = file.parse_front_matter::<FrontMatter>() else {
+            posts = None;
+            continue;
+        };
+        file.non_empty("title", &front_matter.title);
+        file.non_empty("summary", &front_matter.summary);
+        for (index, tag) in front_matter.tags.iter().enumerate() {
+            if slugify(tag).is_empty() {
+                file.error(&format!("tags[{index}]"), format!("`{tag}` has no letters or digits"));
+            }
+        }
+        if body.trim().is_empty() {
+            file.error("", "has no content after the front matter");
+        }
+
+        let words = body.split_whitespace().count();
+        if let Some(posts) = &mut posts {
+            posts.push(Post {
+                slug,
+                title: front_matter.title,
+                date: front_matter.date,
+                tags: front_matter.tags,
+                summary: front_matter.summary,
+                html: render_markdown(&body, &syntaxes),
+                reading_minutes: words.div_ceil(WORDS_PER_MINUTE).max(1) as u32,
+            });
+        }
+    }
+
+    match (profile, resume, projects, about, terms, privacy, theme, posts) {
+        (
+            Some(profile),
+            Some(resume),
+            Some(projects),
+            Some(about),
+            Some(terms),
+            Some(privacy),
+            Some(theme),
+            Some(posts),
+        ) if errors.is_empty() => Datafiles {
+            profile,
+            resume,
+            projects,
+            about,
+            terms,
+            privacy,
+            theme,
+            posts,
+        },
+        _ => panic!("Invalid datafiles:\n{}", errors.join("\n")),
+    }
+}
+
+/// Renders a post's markdown the way `markdown::render` does at runtime, except that fenced code
+/// blocks are highlighted with the classes styled by [`generate_highlight_css`].
+fn render_markdown(source: &str, syntaxes: &SyntaxSet) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut events = Vec::new();
+    let mut code_block = None::<(String, String)>;
+    // Sanitized before the highlighted code is added, which is the only html a post may contain
+    for event in markdown::sanitize::sanitize(Parser::new_ext(source, options)) {
+        match (event, &mut code_block) {
+            (Event::Start(Tag::CodeBlock(kind)), _) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split(|c: char| c.is_whitespace() || c == ',')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code_block = Some((language, String::new()));
+            }
+            (Event::Text(text), Some((_, code))) => code.push_str(&text),
+            (Event::End(TagEnd::CodeBlock), Some((language, code))) => {
+                events.push(Event::Html(highlight(language, code, syntaxes).into()));
+                code_block = None;
+            }
+            (event, _) => events.push(event),
+        }
+    }
+
+    let mut output = String::with_capacity(source.len() * 2);
+    html::push_html(&mut output, events.into_iter());
+    output
+}
+
+/// A code block as html, with the language's tokens wrapped in classed spans. Unknown languages
+/// are left plain.
+fn highlight(language: &str, code: &str, syntaxes: &SyntaxSet) -> String {
+    let syntax = syntaxes
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut generator =
+        ClassedHTMLGenerator::new_with_class_style(syntax, syntaxes, CODE_CLASS_STYLE);
+    for line in LinesWithEndings::from(code) {
+        generator
+            .parse_html_for_line_which_includes_newline(line)
+            .expect("Failed to highlight code block");
+    }
+    let language = language
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '-' | '+' | '#'))
+        .collect::<String>();
+    format!(
+        r#"<pre class="hl-code"><code class="language-{language}">{}</code></pre>"#,
+        generator.finalize()
+    )
+}
+
+/// Colors for the highlighted code of each theme, scoped by the `data-theme` the theme toggle
+/// sets on the root element.
+fn generate_highlight_css() {
+    let themes = ThemeSet::load_defaults();
+    let mut css = String::new();
+    for (mode, name) in CODE_THEMES {
+        let scope = match mode {
+            "light" => r#":root[data-theme="light"]"#,
+            _ => r#":root:not([data-theme="light"])"#,
+        };
+        let rules = css_for_theme_with_class_style(&themes.themes[name], CODE_CLASS_STYLE)
+            .expect("Failed to generate code highlighting css");
+        for line in rules.lines() {
+            match line.strip_suffix('{') {
+                Some(selectors) if !line.starts_with(char::is_whitespace) => {
+                    let selectors = selectors
+                        .split(',')
+                        .map(|selector| format!("{scope} {}", selector.trim()))
+                        .collect::<Vec<_>>();
+                    writeln!(css, "{} {{", selectors.join(", ")).unwrap();
+                }
+                _ => writeln!(css, "{line}").unwrap(),
+            }
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("highlight.css"), css).expect("Failed to write highlight css");
+}
+
+/// Writes a value as the Rust expression that builds it.
+trait Literal {
+    fn literal(&self) -> String;
+}
+
+impl Literal for String {
+    fn literal(&self) -> String {
+        format!("String::from({self:?})")
+    }
+}
+
+impl Literal for bool {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Literal for u8 {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Literal for u32 {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Literal for i32 {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: Literal> Literal for Option<T> {
+    fn literal(&self) -> String {
+        match self {
+            Some(value) => format!("Some({})", value.literal()),
+            None => "None".to_string(),
+        }
+    }
+}
+
+impl<T: Literal> Literal for Vec<T> {
+    fn literal(&self) -> String {
+        let items = self.iter().map(Literal::literal).collect::<Vec<_>>();
+        format!("vec![{}]", items.join(", "))
+    }
+}
+
+impl Literal for DateEnd {
+    fn literal(&self) -> String {
+        match self {
+            DateEnd::Present => "DateEnd::Present".to_string(),
+            DateEnd::On(date) => format!("DateEnd::On({})", date.literal()),
+        }
+    }
+}
+
+macro_rules! literal_struct {
+    ($($name:ident { $($field:ident),* $(,)? })*) => {$(
+        impl Literal for $name {
+            fn literal(&self) -> String {
+                let mut literal = String::from(concat!(stringify!($name), " {"));
+                $(write!(literal, " {}: {},", stringify!($field), self.$field.literal()).unwrap();)*
+                literal.push_str(" }");
+                literal
+            }
+        }
+    )*};
+}
+
+literal_struct! {
+    YearMonth { year, month }
+    Date { year, month, day }
+    Dates { start, end }
+    Profile {
+        name, title, headline, email, phone, location, linkedin, github, website, avatar, social,
+    }
+    SocialLink { network, url, label }
+    Experience { position, company, dates, description, technologies }
+    Education { degree, school, dates, details }
+    SkillCategory { name, items }
+    Resume { experience, education, skills }
+    Project {
+        name, description, keywords, website, github, image, dates, featured, body, role, outcome,
+        screenshots,
+    }
+    AboutSection { title, content }
+    About { intro, sections, interests, values }
+    LegalSection { title, content }
+    Terms { title, effective_date, sections }
+    Privacy { title, effective_date, sections }
+    Theme { dark, light, spacing, radii, breakpoints }
+    Palette {
+        background, surface, surface_raised, border, heading, text, text_secondary, text_muted,
+        text_subtle, primary, secondary, link, link_strong, accent, success, success_text, warning,
+        danger, overlay, shadow, toast,
+    }
+    Spacing { xs, sm, md, lg, xl }
+    Radii { sm, md, lg, pill }
+    Breakpoints { mobile }
+    Post { slug, title, date, tags, summary, html, reading_minutes }
+}
+
+/// Compiles the datafiles into functions that build them, so the site never parses json for its
+/// own content.
+fn generate_datafiles(mut datafiles: Datafiles) {
+    datafiles
+        .projects
+        .sort_by_key(|project| std::cmp::Reverse(project.dates.latest()));
+    datafiles
+        .posts
+        .sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.slug.cmp(&b.slug)));
+
+    let mut code = String::new();
+    let mut function = |cfg: &str, name: &str, ty: &str, value: String| {
+        writeln!(code, "{cfg}pub fn {name}() -> {ty} {{\n    {value}\n}}\n").unwrap();
+    };
+    function("", "profile", "Profile", datafiles.profile.literal());
+    function("", "resume", "Resume", datafiles.resume.literal());
+    function("", "projects", "Vec<Project>", datafiles.projects.literal());
+    function("", "about", "About", datafiles.about.literal());
+    let legal = "#[cfg(feature = \"legal-pages\")]\n";
+    function(legal, "terms", "Terms", datafiles.terms.literal());
+    function(legal, "privacy", "Privacy", datafiles.privacy.literal());
+    function("", "theme", "Theme", datafiles.theme.literal());
+    function("", "posts", "Vec<Post>", datafiles.posts.literal());
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("datafiles.rs"), code).expect("Failed to write datafiles");
+}
+
+fn image_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            image_files(&path, files);
+        } else if ImageFormat::from_path(&path)
+            .is_ok_and(|format| matches!(format, ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP))
+        {
+            files.push(path);
+        }
+    }
+}
+
+/// Records the dimensions and a tiny placeholder of every image so views can reserve space and
+/// blur the image in without fetching anything first.
+fn generate_image_manifest() {
+    println!("cargo:rerun-if-changed={IMAGE_DIR}");
+
+    let mut files = Vec::new();
+    image_files(Path::new(IMAGE_DIR), &mut files);
+    files.sort();
+
+    let mut manifest = String::from("&[\n");
+    for file in files {
+        let image = image::open(&file)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", file.display()));
+
+        // At least a pixel tall, or very wide images would make an empty placeholder
+        let height = (PLACEHOLDER_WIDTH * image.height() / image.width().max(1)).max(1);
+        let mut placeholder = Vec::new();
+        image
+            .thumbnail(PLACEHOLDER_WIDTH, height)
+            .write_to(&mut std::io::Cursor::new(&mut placeholder), ImageFormat::Png)
+            .expect("Failed to encode placeholder");
+
+        let path = file
+            .strip_prefix(IMAGE_DIR)
+            .expect("image inside the image directory")
+            .to_string_lossy()
+            .replace('\\', "/");
+        writeln!(
+            manifest,
+            "    ImageMeta {{ path: {path:?}, width: {}, height: {}, placeholder: \"data:image/png;base64,{}\" }},",
+            image.width(),
+            image.height(),
+            base64::engine::general_purpose::STANDARD.encode(placeholder),
+        )
+        .unwrap();
+    }
+    manifest.push(']');
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("images.rs"), manifest).expect("Failed to write image manifest");
+}
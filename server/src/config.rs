@@ -0,0 +1,106 @@
+use resume_portfolio::data::get_profile;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+const DEFAULT_BIND_ADDR: &str = "127.0.0.1:8081";
+const DEFAULT_SMTP_PORT: u16 = 587;
+
+#[derive(Debug)]
+pub struct ConfigError(String);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug)]
+pub enum MailConfig {
+    /// Writes every message as an `.eml` file, for local testing.
+    File { dir: PathBuf },
+    Smtp {
+        host: String,
+        port: u16,
+        username: Option<String>,
+        password: Option<String>,
+        tls: bool,
+    },
+}
+
+#[derive(Debug)]
+pub struct Config {
+    pub bind: SocketAddr,
+    pub data_dir: PathBuf,
+    pub allowed_origin: Option<String>,
+    pub mail_from: String,
+    pub mail_to: String,
+    pub mail: MailConfig,
+}
+
+fn var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: String) -> Result<T, ConfigError> {
+    value
+        .parse()
+        .map_err(|_| ConfigError(format!("{name} has an invalid value: {value}")))
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let bind = parse(
+            "BIND_ADDR",
+            var("BIND_ADDR").unwrap_or_else(|| DEFAULT_BIND_ADDR.to_string()),
+        )?;
+        let data_dir = PathBuf::from(var("DATA_DIR").unwrap_or_else(|| "data".to_string()));
+
+        // Messages go to the site owner unless told otherwise
+        let mail_to = var("MAIL_TO")
+            .or_else(|| get_profile().email.clone())
+            .ok_or_else(|| ConfigError("MAIL_TO is not set and profile.json has no email".into()))?;
+        let mail_from = var("MAIL_FROM").unwrap_or_else(|| mail_to.clone());
+
+        let mail = match var("MAIL_TRANSPORT").as_deref().unwrap_or("file") {
+            "file" => MailConfig::File {
+                dir: var("MAIL_DIR")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| data_dir.join("outbox")),
+            },
+            "smtp" => MailConfig::Smtp {
+                host: var("SMTP_HOST")
+                    .ok_or_else(|| ConfigError("SMTP_HOST is required for the smtp transport".into()))?,
+                port: var("SMTP_PORT")
+                    .map(|port| parse("SMTP_PORT", port))
+                    .transpose()?
+                    .unwrap_or(DEFAULT_SMTP_PORT),
+                username: var("SMTP_USERNAME"),
+                password: var("SMTP_PASSWORD"),
+                tls: var("SMTP_TLS")
+                    .map(|tls| parse("SMTP_TLS", tls))
+                    .transpose()?
+                    .unwrap_or(true),
+            },
+            other => {
+                return Err(ConfigError(format!(
+                    "MAIL_TRANSPORT must be `file` or `smtp`, got `{other}`"
+                )))
+            }
+        };
+
+        Ok(Self {
+            bind,
+            data_dir,
+            allowed_origin: var("ALLOWED_ORIGIN"),
+            mail_from,
+            mail_to,
+            mail,
+        })
+    }
+}
@@ -0,0 +1,254 @@
+use crate::error::ApiError;
+use crate::mailer::Mailer;
+use crate::store::SubmissionStore;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::State;
+use axum::Json;
+use lettre::message::{Mailbox, Message};
+use lettre::Address;
+use resume_portfolio::data::ContactForm;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const MAX_NAME_LEN: usize = 100;
+const MAX_EMAIL_LEN: usize = 254;
+const MAX_PHONE_LEN: usize = 32;
+const MAX_MESSAGE_LEN: usize = 5000;
+
+pub struct AppState<M> {
+    pub store: SubmissionStore,
+    pub mailer: M,
+    pub from: Mailbox,
+    pub to: Mailbox,
+}
+
+#[derive(Serialize)]
+pub struct Received {
+    id: String,
+    status: &'static str,
+}
+
+/// Trims every field and checks it, returning the cleaned form and the sender's address.
+fn validate(form: ContactForm) -> Result<(ContactForm, Address), ApiError> {
+    let form = ContactForm {
+        name: form.name.trim().to_string(),
+        email: form.email.trim().to_string(),
+        phone: form.phone.trim().to_string(),
+        message: form.message.trim().to_string(),
+        website: form.website.trim().to_string(),
+    };
+    let mut errors = BTreeMap::new();
+
+    if !form.website.is_empty() {
+        errors.insert("website", "The message looks automated and was not sent.");
+    }
+
+    if form.name.is_empty() {
+        errors.insert("name", "Name is required.");
+    } else if form.name.chars().count() > MAX_NAME_LEN {
+        errors.insert("name", "Name is too long.");
+    }
+
+    let address = if form.email.is_empty() {
+        errors.insert("email", "Email is required.");
+        None
+    } else if form.email.len() > MAX_EMAIL_LEN {
+        errors.insert("email", "Email is too long.");
+        None
+    } else {
+        let address = form.email.parse::<Address>().ok();
+        if address.is_none() {
+            errors.insert("email", "Email is not a valid address.");
+        }
+        address
+    };
+
+    let phone_chars_valid = form
+        .phone
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '+' | '-' | '(' | ')' | '.'));
+    if form.phone.len() > MAX_PHONE_LEN || !phone_chars_valid {
+        errors.insert("phone", "Phone number is not valid.");
+    }
+
+    if form.message.is_empty() {
+        errors.insert("message", "Message is required.");
+    } else if form.message.chars().count() > MAX_MESSAGE_LEN {
+        errors.insert("message", "Message is too long.");
+    }
+
+    match address {
+        Some(address) if errors.is_empty() => Ok((form, address)),
+        _ => Err(ApiError::Validation(errors)),
+    }
+}
+
+fn compose<M>(state: &AppState<M>, form: &ContactForm, sender: Address) -> Option<Message> {
+    let phone = if form.phone.is_empty() {
+        "not provided"
+    } else {
+        &form.phone
+    };
+    let body = format!(
+        "Name: {}\nEmail: {}\nPhone: {phone}\n\n{}\n",
+        form.name, form.email, form.message
+    );
+
+    Message::builder()
+        .from(state.from.clone())
+        .reply_to(Mailbox::new(Some(form.name.clone()), sender))
+        .to(state.to.clone())
+        .subject(format!("New message from {}", form.name))
+        .body(body)
+        .ok()
+}
+
+pub async fn submit<M: Mailer>(
+    State(state): State<Arc<AppState<M>>>,
+    payload: Result<Json<ContactForm>, JsonRejection>,
+) -> Result<Json<Received>, ApiError> {
+    let Json(form) = payload.map_err(|rejection| ApiError::InvalidBody(rejection.body_text()))?;
+    let (form, sender) = validate(form)?;
+
+    let id = state.store.append(&form).await.map_err(|err| {
+        eprintln!("Failed to store submission: {err}");
+        ApiError::Storage
+    })?;
+
+    // The submission is already stored, so a relay failure is logged rather than surfaced to
+    // the visitor
+    match compose(&state, &form, sender) {
+        Some(message) => {
+            if let Err(err) = state.mailer.send(message).await {
+                eprintln!("Failed to relay submission {id}: {err}");
+            }
+        }
+        None => eprintln!("Failed to compose message for submission {id}"),
+    }
+
+    Ok(Json(Received {
+        id,
+        status: "received",
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, MAX_MESSAGE_LEN, MAX_NAME_LEN};
+    use crate::error::ApiError;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use resume_portfolio::data::ContactForm;
+    use serde_json::{json, Value};
+
+    fn form() -> ContactForm {
+        ContactForm {
+            name: "Ada Lovelace".to_string(),
+            email: "ada@example.com".to_string(),
+            phone: "+1 (555) 123-4567".to_string(),
+            message: "Hello there".to_string(),
+            website: String::new(),
+        }
+    }
+
+    /// The field errors of a rejected form.
+    fn rejected(form: ContactForm) -> Vec<(&'static str, &'static str)> {
+        match validate(form) {
+            Err(ApiError::Validation(errors)) => errors.into_iter().collect(),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn accepts_a_valid_form_and_trims_it() {
+        let (form, address) = validate(ContactForm {
+            name: "  Ada Lovelace ".to_string(),
+            ..form()
+        })
+        .expect("valid form");
+        assert_eq!(form.name, "Ada Lovelace");
+        assert_eq!(address.to_string(), "ada@example.com");
+    }
+
+    #[test]
+    fn rejects_an_empty_or_long_name() {
+        let empty = ContactForm {
+            name: "   ".to_string(),
+            ..form()
+        };
+        assert_eq!(rejected(empty), [("name", "Name is required.")]);
+
+        let long = ContactForm {
+            name: "a".repeat(MAX_NAME_LEN + 1),
+            ..form()
+        };
+        assert_eq!(rejected(long), [("name", "Name is too long.")]);
+    }
+
+    #[test]
+    fn rejects_an_empty_or_long_message() {
+        let empty = ContactForm {
+            message: String::new(),
+            ..form()
+        };
+        assert_eq!(rejected(empty), [("message", "Message is required.")]);
+
+        let long = ContactForm {
+            message: "a".repeat(MAX_MESSAGE_LEN + 1),
+            ..form()
+        };
+        assert_eq!(rejected(long), [("message", "Message is too long.")]);
+    }
+
+    #[test]
+    fn rejects_a_malformed_email() {
+        for email in ["ada", "ada@", "@example.com", "ada example.com"] {
+            let form = ContactForm {
+                email: email.to_string(),
+                ..form()
+            };
+            assert_eq!(rejected(form), [("email", "Email is not a valid address.")]);
+        }
+    }
+
+    #[test]
+    fn rejects_a_filled_honeypot() {
+        let form = ContactForm {
+            website: "https://spam.example".to_string(),
+            ..form()
+        };
+        assert_eq!(
+            rejected(form),
+            [("website", "The message looks automated and was not sent.")]
+        );
+    }
+
+    #[tokio::test]
+    async fn explains_rejections_in_the_json_body() {
+        let error = validate(ContactForm {
+            name: String::new(),
+            email: "ada".to_string(),
+            ..form()
+        })
+        .expect_err("invalid form");
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let body = serde_json::from_slice::<Value>(&body).expect("json body");
+        assert_eq!(
+            body,
+            json!({
+                "error": "validation_failed",
+                "message": "Email is not a valid address. Name is required.",
+                "fields": {
+                    "email": "Email is not a valid address.",
+                    "name": "Name is required.",
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Errors returned to the contact form as `{ "error", "message", "fields"? }` JSON.
+#[derive(Debug)]
+pub enum ApiError {
+    InvalidBody(String),
+    Validation(BTreeMap<&'static str, &'static str>),
+    Storage,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, body) = match self {
+            ApiError::InvalidBody(reason) => (
+                StatusCode::BAD_REQUEST,
+                json!({
+                    "error": "invalid_body",
+                    "message": format!("The request could not be read: {reason}"),
+                }),
+            ),
+            ApiError::Validation(fields) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                json!({
+                    "error": "validation_failed",
+                    "message": fields.values().copied().collect::<Vec<_>>().join(" "),
+                    "fields": fields,
+                }),
+            ),
+            ApiError::Storage => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                json!({
+                    "error": "storage_failed",
+                    "message": "Your message could not be saved. Please try again later.",
+                }),
+            ),
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
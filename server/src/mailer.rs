@@ -0,0 +1,85 @@
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncFileTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::fmt;
+use std::future::Future;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct MailError(String);
+
+impl fmt::Display for MailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for MailError {}
+
+/// Relays contact submissions to the site owner.
+pub trait Mailer: Send + Sync + 'static {
+    fn send(&self, message: Message) -> impl Future<Output = Result<(), MailError>> + Send;
+}
+
+/// Stand-in transport that writes each message to `<dir>/<uuid>.eml`.
+pub struct FileMailer {
+    transport: AsyncFileTransport<Tokio1Executor>,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            transport: AsyncFileTransport::new(dir),
+        }
+    }
+}
+
+impl Mailer for FileMailer {
+    async fn send(&self, message: Message) -> Result<(), MailError> {
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|err| MailError(err.to_string()))
+    }
+}
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    /// Connects with STARTTLS when `tls` is set, or in plain text for local relays such as
+    /// MailHog or `python -m smtpd`.
+    pub fn new(
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+        tls: bool,
+    ) -> Result<Self, MailError> {
+        let mut builder = if tls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|err| MailError(err.to_string()))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        }
+        .port(port);
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+        })
+    }
+}
+
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: Message) -> Result<(), MailError> {
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|err| MailError(err.to_string()))
+    }
+}
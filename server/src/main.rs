@@ -0,0 +1,95 @@
+//! Implements the `/v1/contact` api the site's contact form posts to.
+//!
+//! Configured through the environment:
+//! - `BIND_ADDR` (default `127.0.0.1:8081`) and `ALLOWED_ORIGIN` for CORS (default any)
+//! - `DATA_DIR` (default `data`) where `submissions.jsonl` is appended to
+//! - `MAIL_TO` (default the email in `profile.json`) and `MAIL_FROM` (default `MAIL_TO`)
+//! - `MAIL_TRANSPORT`: `file` (default) writes `.eml` files to `MAIL_DIR`, `smtp` relays through
+//!   `SMTP_HOST`, `SMTP_PORT`, `SMTP_USERNAME`, `SMTP_PASSWORD` and `SMTP_TLS`
+
+mod config;
+mod contact;
+mod error;
+mod mailer;
+mod store;
+
+use axum::http::{header, HeaderValue, Method};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use config::{Config, MailConfig};
+use contact::AppState;
+use lettre::message::Mailbox;
+use mailer::{FileMailer, Mailer, SmtpMailer};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use store::SubmissionStore;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+async fn health() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+fn cors(allowed_origin: Option<&str>) -> Result<CorsLayer, BoxError> {
+    let origin = match allowed_origin {
+        Some(origin) => AllowOrigin::exact(HeaderValue::from_str(origin)?),
+        None => AllowOrigin::any(),
+    };
+
+    Ok(CorsLayer::new()
+        .allow_origin(origin)
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::CONTENT_TYPE]))
+}
+
+async fn serve<M: Mailer>(config: Config, mailer: M) -> Result<(), BoxError> {
+    let state = AppState {
+        store: SubmissionStore::open(&config.data_dir).await?,
+        mailer,
+        from: config.mail_from.parse::<Mailbox>()?,
+        to: config.mail_to.parse::<Mailbox>()?,
+    };
+
+    let app = Router::new()
+        .route("/v1/contact", post(contact::submit::<M>))
+        .route("/v1/health", get(health))
+        .layer(cors(config.allowed_origin.as_deref())?)
+        .with_state(Arc::new(state));
+
+    let listener = tokio::net::TcpListener::bind(config.bind).await?;
+    println!("📬 Listening on http://{}", listener.local_addr()?);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), BoxError> {
+    let config = Config::from_env()?;
+
+    match &config.mail {
+        MailConfig::File { dir } => {
+            tokio::fs::create_dir_all(dir).await?;
+            println!("✉️  Writing messages to {}", dir.display());
+            let mailer = FileMailer::new(dir);
+            serve(config, mailer).await
+        }
+        MailConfig::Smtp {
+            host,
+            port,
+            username,
+            password,
+            tls,
+        } => {
+            let credentials = username.clone().zip(password.clone());
+            let mailer = SmtpMailer::new(host, *port, credentials, *tls)?;
+            serve(config, mailer).await
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use resume_portfolio::data::ContactForm;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+const SUBMISSIONS_FILE: &str = "submissions.jsonl";
+
+#[derive(Serialize)]
+struct Submission<'a> {
+    id: &'a str,
+    received_at: u64,
+    #[serde(flatten)]
+    form: &'a ContactForm,
+}
+
+/// Append-only log of every submission, one JSON object per line.
+pub struct SubmissionStore {
+    path: PathBuf,
+    counter: AtomicU32,
+    lock: Mutex<()>,
+}
+
+impl SubmissionStore {
+    pub async fn open(dir: &Path) -> std::io::Result<Self> {
+        tokio::fs::create_dir_all(dir).await?;
+        Ok(Self {
+            path: dir.join(SUBMISSIONS_FILE),
+            counter: AtomicU32::new(0),
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Persists `form` and returns the id assigned to it.
+    pub async fn append(&self, form: &ContactForm) -> std::io::Result<String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let sequence = self.counter.fetch_add(1, Ordering::Relaxed);
+        let id = format!("{:x}-{sequence:04x}", now.as_millis());
+
+        let mut line = serde_json::to_vec(&Submission {
+            id: &id,
+            received_at: now.as_secs(),
+            form,
+        })?;
+        line.push(b'\n');
+
+        let _guard = self.lock.lock().await;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(&line).await?;
+        file.flush().await?;
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,90 @@
+//! Converts between the datafiles and a JSON Resume (https://jsonresume.org).
+//!
+//!     cargo run -p site-gen --bin json-resume -- export [resume.json]
+//!     cargo run -p site-gen --bin json-resume -- import resume.json
+
+use resume_portfolio::data::{get_about, get_profile, get_projects, get_resume};
+use resume_portfolio::export::json_resume::{self, JsonResume, MappingReport};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage: json-resume export [FILE] | json-resume import FILE";
+
+fn datafile_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/datafile")
+}
+
+fn warn_unmapped(report: &MappingReport) {
+    if !report.is_empty() {
+        eprintln!(
+            "⚠️  {} field(s) could not be mapped:",
+            report.unmapped.len()
+        );
+        for path in &report.unmapped {
+            eprintln!("   {path}");
+        }
+    }
+}
+
+fn write_json(path: &Path, value: &impl Serialize) -> std::io::Result<()> {
+    let mut json = serde_json::to_string_pretty(value).map_err(std::io::Error::other)?;
+    json.push('\n');
+    std::fs::write(path, json)?;
+    println!("📄 Wrote {}", path.display());
+    Ok(())
+}
+
+fn export(output: Option<&str>) -> std::io::Result<()> {
+    let (json, report) =
+        json_resume::export(get_profile(), get_about(), get_resume(), get_projects());
+    match output {
+        Some(path) => write_json(Path::new(path), &json)?,
+        None => println!(
+            "{}",
+            serde_json::to_string_pretty(&json).map_err(std::io::Error::other)?
+        ),
+    }
+    warn_unmapped(&report);
+    Ok(())
+}
+
+fn import(input: &str) -> std::io::Result<()> {
+    let json = std::fs::read_to_string(input)?;
+    let json: JsonResume = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+    let (mut imported, report) = json_resume::import(&json);
+    imported.keep_local_fields(get_profile(), get_resume(), get_projects());
+
+    let mut about = get_about().clone();
+    if let Some(summary) = imported.summary {
+        about.intro = summary;
+    }
+    if !imported.interests.is_empty() {
+        about.interests = imported.interests;
+    }
+
+    let dir = datafile_dir();
+    write_json(&dir.join("profile.json"), &imported.profile)?;
+    write_json(&dir.join("resume.json"), &imported.resume)?;
+    write_json(&dir.join("projects.json"), &imported.projects)?;
+    write_json(&dir.join("about.json"), &about)?;
+    warn_unmapped(&report);
+    Ok(())
+}
+
+fn main() -> std::io::Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["export"] => export(None),
+        ["export", output] => export(Some(output)),
+        ["import", input] => import(input),
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
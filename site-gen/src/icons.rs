@@ -0,0 +1,149 @@
+use crate::write;
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ExtendedColorType, ImageEncoder, RgbaImage};
+use resume_portfolio::data::{get_profile, get_theme};
+use resume_portfolio::images;
+use std::path::Path;
+
+/// Sizes packed into `favicon.ico`.
+const ICO_SIZES: [u32; 3] = [16, 32, 48];
+
+/// Png icons linked from `index.html`, with their size.
+const PNG_ICONS: [(&str, u32); 2] = [("favicon-32.png", 32), ("apple-touch-icon.png", 180)];
+
+/// Where the icons are drawn from.
+enum Source {
+    Avatar(DynamicImage),
+    Initials(String),
+}
+
+impl Source {
+    fn render(&self, size: u32) -> std::io::Result<RgbaImage> {
+        match self {
+            Source::Avatar(avatar) => Ok(avatar
+                .resize_exact(size, size, FilterType::Lanczos3)
+                .to_rgba8()),
+            // Touch icons get a square background since iOS fills transparent corners with black
+            Source::Initials(initials) => render_svg(&initials_svg(initials, size < 64), size),
+        }
+    }
+}
+
+/// A gradient badge with the initials, styled like the sidebar avatar.
+fn initials_svg(initials: &str, round: bool) -> String {
+    let radius = if round { 32 } else { 0 };
+    let font_size = if initials.chars().count() > 2 { 20 } else { 26 };
+    let initials = initials
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    // Icons have no color scheme, so they take the colors of the dark palette the site starts in
+    let palette = &get_theme().dark;
+    let (primary, accent) = (&palette.primary, &palette.accent);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="64" height="64" viewBox="0 0 64 64">
+  <defs>
+    <linearGradient id="background" x1="0" y1="1" x2="1" y2="0">
+      <stop offset="0" stop-color="{primary}"/>
+      <stop offset="1" stop-color="{accent}"/>
+    </linearGradient>
+  </defs>
+  <rect width="64" height="64" rx="{radius}" fill="url(#background)"/>
+  <text x="32" y="32" dominant-baseline="central" text-anchor="middle" font-family="Inter, DejaVu Sans, sans-serif" font-weight="bold" font-size="{font_size}" fill="white">{initials}</text>
+</svg>"##
+    )
+}
+
+fn render_svg(svg: &str, size: u32) -> std::io::Result<RgbaImage> {
+    let mut options = resvg::usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    let tree = resvg::usvg::Tree::from_str(svg, &options).map_err(std::io::Error::other)?;
+
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(size, size)
+        .ok_or_else(|| std::io::Error::other("icon size must not be zero"))?;
+    let scale = size as f32 / tree.size().width();
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    // Pixmaps are premultiplied, images are not
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    RgbaImage::from_raw(size, size, pixels)
+        .ok_or_else(|| std::io::Error::other("icon pixels do not match its size"))
+}
+
+fn encode_png(image: &RgbaImage) -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    PngEncoder::new(&mut bytes)
+        .write_image(
+            image,
+            image.width(),
+            image.height(),
+            ExtendedColorType::Rgba8,
+        )
+        .map_err(std::io::Error::other)?;
+    Ok(bytes)
+}
+
+/// The avatar cropped to a square, or the initials when there is no avatar in the repository.
+fn source() -> std::io::Result<Source> {
+    let profile = get_profile();
+    let initials = Source::Initials(profile.initials());
+    let Some(avatar) = profile.avatar.as_deref() else {
+        return Ok(initials);
+    };
+    if avatar.contains("://") {
+        println!("ℹ️  Avatar is hosted elsewhere, drawing the icons from the initials");
+        return Ok(initials);
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(images::SOURCE_DIR)
+        .join(avatar.trim_start_matches('/'));
+    let avatar = image::open(&path).map_err(std::io::Error::other)?;
+    let size = avatar.width().min(avatar.height());
+    let avatar = avatar.crop_imm(
+        (avatar.width() - size) / 2,
+        (avatar.height() - size) / 2,
+        size,
+        size,
+    );
+    Ok(Source::Avatar(avatar))
+}
+
+/// Writes `favicon.ico`, a png favicon and the Apple touch icon.
+pub fn write_icons(dir: &Path) -> std::io::Result<()> {
+    let source = source()?;
+
+    let frames = ICO_SIZES
+        .into_iter()
+        .map(|size| {
+            let icon = source.render(size)?;
+            IcoFrame::as_png(icon.as_raw(), size, size, ExtendedColorType::Rgba8)
+                .map_err(std::io::Error::other)
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let mut ico = Vec::new();
+    IcoEncoder::new(&mut ico)
+        .encode_images(&frames)
+        .map_err(std::io::Error::other)?;
+    write(dir, "favicon.ico", &ico)?;
+
+    for (name, size) in PNG_ICONS {
+        write(dir, name, &encode_png(&source.render(size)?)?)?;
+    }
+
+    Ok(())
+}
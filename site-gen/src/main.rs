@@ -0,0 +1,201 @@
+mod icons;
+
+use image::codecs::avif::AvifEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use resume_portfolio::data::{get_about, get_posts, get_profile, get_projects, get_resume};
+use resume_portfolio::export::{feed, json_resume, pdf};
+use resume_portfolio::{images, prerender, sitemap};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+// Trade a little compression for encoding time
+const AVIF_SPEED: u8 = 8;
+const AVIF_QUALITY: u8 = 70;
+
+// Trunk exposes the staging directory to hooks, so running this as a post-build hook writes
+// straight into the final `dist/`
+fn output_dir() -> PathBuf {
+    std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var("TRUNK_STAGING_DIR").ok())
+        .unwrap_or_else(|| "dist".to_string())
+        .into()
+}
+
+fn write(dir: &Path, name: &str, contents: &[u8]) -> std::io::Result<()> {
+    let path = dir.join(name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, contents)?;
+    println!("📄 Wrote {}", path.display());
+    Ok(())
+}
+
+fn write_json<T: Serialize + ?Sized>(dir: &Path, name: &str, value: &T) -> std::io::Result<()> {
+    let json = serde_json::to_vec_pretty(value).map_err(std::io::Error::other)?;
+    write(dir, name, &json)
+}
+
+/// Copies of the content in the datafile format, for sites built with `CONTENT_URL=/data` that
+/// load it at startup so it can be edited in `dist/data/` without a rebuild.
+fn write_content(dir: &Path) -> std::io::Result<()> {
+    let dir = dir.join("data");
+    write_json(&dir, "profile.json", get_profile())?;
+    write_json(&dir, "resume.json", get_resume())?;
+    write_json(&dir, "projects.json", get_projects())?;
+    write_json(&dir, "about.json", get_about())?;
+    #[cfg(feature = "legal-pages")]
+    {
+        write_json(&dir, "terms.json", resume_portfolio::data::get_terms())?;
+        write_json(&dir, "privacy.json", resume_portfolio::data::get_privacy())?;
+    }
+    Ok(())
+}
+
+/// Feeds of the projects and posts, which need the profile's website for absolute urls.
+fn write_feeds(dir: &Path) -> std::io::Result<()> {
+    let Some(feed) = feed::Feed::new(get_profile(), get_projects(), get_posts()) else {
+        println!("ℹ️  Skipping feeds, the profile has no website to link to");
+        return Ok(());
+    };
+    for format in feed::Format::ALL {
+        write(dir, format.file_name(), feed.render(format).as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Paths crawlers should stay out of, from a comma separated `ROBOTS_DISALLOW` such as
+/// `/drafts,/private`, or `/` to keep a staging deployment out of search results.
+fn robots_disallow() -> Vec<String> {
+    std::env::var("ROBOTS_DISALLOW")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The sitemap, which needs the profile's website for absolute urls, and the `robots.txt`.
+fn write_sitemap(dir: &Path) -> std::io::Result<()> {
+    let website = get_profile().website.as_deref();
+    match website {
+        Some(website) => write(dir, sitemap::SITEMAP_FILE_NAME, sitemap::render(website).as_bytes())?,
+        None => println!("ℹ️  Skipping sitemap, the profile has no website to link to"),
+    }
+    let robots = sitemap::robots(website, &robots_disallow());
+    write(dir, sitemap::ROBOTS_FILE_NAME, robots.as_bytes())
+}
+
+fn encode(image: &DynamicImage, width: u32, extension: &str) -> image::ImageResult<Vec<u8>> {
+    let resized = if width == image.width() {
+        image.clone()
+    } else {
+        image.resize(width, u32::MAX, FilterType::Lanczos3)
+    };
+    let mut bytes = Vec::new();
+    match extension {
+        "avif" => resized.write_with_encoder(AvifEncoder::new_with_speed_quality(
+            &mut bytes,
+            AVIF_SPEED,
+            AVIF_QUALITY,
+        ))?,
+        _ => resized
+            .to_rgba8()
+            .write_with_encoder(WebPEncoder::new_lossless(&mut bytes))?,
+    }
+    Ok(bytes)
+}
+
+/// Writes the resized avif and webp variants referenced by the `srcset` of every image.
+///
+/// Encoding is slow, so variants are kept in `target/` and only redone when the source changes.
+fn write_image_variants(dir: &Path) -> std::io::Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let source_dir = root.join(images::SOURCE_DIR);
+    let cache_dir = root.join("target").join("image-variants");
+    let output_dir = dir.join(images::PUBLIC_PATH.trim_start_matches('/'));
+
+    for meta in images::all() {
+        let source = source_dir.join(meta.path);
+        let modified = std::fs::metadata(&source)?.modified()?;
+        let mut image = None;
+
+        for width in meta.widths() {
+            for (extension, _) in images::VARIANT_FORMATS {
+                let name = meta.variant_path(width, extension);
+                let cached = cache_dir.join(&name);
+                let fresh = std::fs::metadata(&cached)
+                    .and_then(|metadata| metadata.modified())
+                    .is_ok_and(|cached| cached >= modified);
+
+                let bytes = if fresh {
+                    std::fs::read(&cached)?
+                } else {
+                    if image.is_none() {
+                        image = Some(image::open(&source).map_err(std::io::Error::other)?);
+                    }
+                    let bytes = encode(image.as_ref().expect("decoded above"), width, extension)
+                        .map_err(std::io::Error::other)?;
+                    std::fs::create_dir_all(cached.parent().unwrap_or(&cache_dir))?;
+                    std::fs::write(&cached, &bytes)?;
+                    bytes
+                };
+                write(&output_dir, &name, &bytes)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn prerender_routes(dir: &Path) -> std::io::Result<()> {
+    let template = match std::fs::read_to_string(dir.join("index.html")) {
+        Ok(template) => template,
+        Err(err) => {
+            eprintln!("⚠️  Skipping prerender, no index.html in {}: {err}", dir.display());
+            return Ok(());
+        }
+    };
+
+    for path in prerender::paths() {
+        let page = futures::executor::block_on(prerender::render(path));
+        let Some(html) = page.inject(&template) else {
+            eprintln!("⚠️  Skipping prerender, index.html has no empty #app element");
+            return Ok(());
+        };
+        write(dir, &page.file_path(), html.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn main() -> std::io::Result<()> {
+    let dir = output_dir();
+
+    let profile = get_profile();
+    let resume = get_resume();
+    write(&dir, "resume.pdf", &pdf::render_resume(profile, resume))?;
+
+    let (json, report) = json_resume::export(profile, get_about(), resume, get_projects());
+    write_json(&dir, json_resume::FILE_NAME, &json)?;
+    if !report.is_empty() {
+        println!(
+            "ℹ️  Left out of {}: {}",
+            json_resume::FILE_NAME,
+            report.unmapped.join(", ")
+        );
+    }
+
+    write_content(&dir)?;
+    write_feeds(&dir)?;
+    write_sitemap(&dir)?;
+    write_image_variants(&dir)?;
+    icons::write_icons(&dir)?;
+    prerender_routes(&dir)?;
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
-use crate::data::get_about;
-use stylist::{yew::styled_component, Style};
+use super::{Card, MarkdownText, PageHeader, SectionHeader, Tag, TagList};
+use crate::content::use_about;
+use crate::theme;
+use stylist::yew::styled_component;
 use yew::prelude::*;
 
 #[styled_component(AboutView)]
 pub fn about_view() -> Html {
-    let about = get_about();
+    let about = use_about();
 
-    let style = Style::new(css!(
+    let style = css!(
         r#"
         .about-view {
             max-width: 900px;
@@ -14,33 +16,10 @@ pub fn about_view() -> Html {
             padding: 40px 20px;
         }
 
-        .about-header {
-            text-align: center;
-            margin-bottom: 40px;
-        }
-
-        .about-header h1 {
-            font-size: 2.5rem;
-            margin-bottom: 10px;
-            background: linear-gradient(45deg, #3b82f6, #8b5cf6);
-            -webkit-background-clip: text;
-            -webkit-text-fill-color: transparent;
-            background-clip: text;
-        }
-
-        .about-container {
-            background: rgba(255, 255, 255, 0.05);
-            backdrop-filter: blur(10px);
-            border-radius: 16px;
-            padding: 40px;
-            box-shadow: 0 8px 32px rgba(0, 0, 0, 0.2);
-            border: 1px solid rgba(255, 255, 255, 0.1);
-        }
-
         .about-intro {
             font-size: 1.125rem;
             line-height: 1.8;
-            color: #e5e7eb;
+            color: var(--color-text);
             margin-bottom: 40px;
             text-align: center;
             font-weight: 300;
@@ -53,14 +32,14 @@ pub fn about_view() -> Html {
         }
 
         .about-section h3 {
-            color: #60a5fa;
+            color: var(--color-link);
             font-size: 1.25rem;
             margin-bottom: 12px;
             font-weight: 600;
         }
 
         .about-section p {
-            color: #d1d5db;
+            color: var(--color-text-secondary);
             line-height: 1.7;
             font-size: 1rem;
         }
@@ -71,37 +50,7 @@ pub fn about_view() -> Html {
             gap: 40px;
             margin-top: 40px;
             padding-top: 40px;
-            border-top: 1px solid rgba(255, 255, 255, 0.1);
-        }
-
-        .about-interests h3,
-        .about-values h3 {
-            color: #a78bfa;
-            font-size: 1.25rem;
-            margin-bottom: 20px;
-            font-weight: 600;
-        }
-
-        .interests-grid {
-            display: flex;
-            flex-wrap: wrap;
-            gap: 10px;
-        }
-
-        .interest-tag {
-            background: rgba(59, 130, 246, 0.1);
-            border: 1px solid rgba(59, 130, 246, 0.3);
-            color: #93c5fd;
-            padding: 8px 16px;
-            border-radius: 20px;
-            font-size: 0.875rem;
-            transition: all 0.2s;
-        }
-
-        .interest-tag:hover {
-            background: rgba(59, 130, 246, 0.2);
-            border-color: rgba(59, 130, 246, 0.5);
-            transform: translateY(-2px);
+            border-top: 1px solid rgba(var(--color-overlay-rgb), 0.1);
         }
 
         .values-list {
@@ -111,7 +60,7 @@ pub fn about_view() -> Html {
         }
 
         .values-list li {
-            color: #d1d5db;
+            color: var(--color-text-secondary);
             padding-left: 24px;
             position: relative;
             line-height: 1.6;
@@ -122,36 +71,26 @@ pub fn about_view() -> Html {
             content: "▸";
             position: absolute;
             left: 0;
-            color: #a78bfa;
+            color: var(--color-accent);
             font-weight: bold;
         }
 
-        @media (max-width: 768px) {
-            .about-container {
-                padding: 30px 20px;
-            }
-
-            .about-header h1 {
-                font-size: 2rem;
-            }
-
+        @media ${mobile} {
             .about-bottom {
                 grid-template-columns: 1fr;
                 gap: 30px;
             }
         }
-        "#
-    ))
-    .expect("Failed to create style");
+        "#,
+        mobile = theme::mobile(),
+    );
 
     html! {
         <div class={style}>
             <div class="about-view">
-                <div class="about-header">
-                    <h1>{"About Me"}</h1>
-                </div>
+                <PageHeader title="About Me" />
 
-                <div class="about-container">
+                <Card>
                     <div class="about-intro">
                         <p>{&about.intro}</p>
                     </div>
@@ -161,7 +100,7 @@ pub fn about_view() -> Html {
                             html! {
                                 <div class="about-section">
                                     <h3>{&section.title}</h3>
-                                    <p>{&section.content}</p>
+                                    <MarkdownText source={section.content.clone()} />
                                 </div>
                             }
                         })}
@@ -170,20 +109,18 @@ pub fn about_view() -> Html {
                     <div class="about-bottom">
                         if !about.interests.is_empty() {
                             <div class="about-interests">
-                                <h3>{"Areas of Interest"}</h3>
-                                <div class="interests-grid">
+                                <SectionHeader title="Areas of Interest" />
+                                <TagList>
                                     {for about.interests.iter().map(|interest| {
-                                        html! {
-                                            <span class="interest-tag">{interest}</span>
-                                        }
+                                        html! { <Tag>{interest}</Tag> }
                                     })}
-                                </div>
+                                </TagList>
                             </div>
                         }
 
                         if !about.values.is_empty() {
                             <div class="about-values">
-                                <h3>{"Core Values"}</h3>
+                                <SectionHeader title="Core Values" />
                                 <ul class="values-list">
                                     {for about.values.iter().map(|value| {
                                         html! {
@@ -194,7 +131,7 @@ pub fn about_view() -> Html {
                             </div>
                         }
                     </div>
-                </div>
+                </Card>
             </div>
         </div>
     }
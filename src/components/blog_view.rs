@@ -0,0 +1,167 @@
+use super::{Card, NotFoundView, PageHeader, PostMeta, PostTags, Tag, TagList};
+use crate::content::use_posts;
+use crate::data::{slugify, Post};
+use crate::Route;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BlogViewProps {
+    /// Slug of the tag to list the posts of, instead of every post.
+    #[prop_or_default]
+    pub tag: Option<String>,
+}
+
+#[styled_component(BlogView)]
+pub fn blog_view(props: &BlogViewProps) -> Html {
+    let posts = use_posts();
+    let tagged = posts
+        .iter()
+        .filter(|post| props.tag.as_ref().is_none_or(|tag| post.has_tag(tag)))
+        .collect::<Vec<_>>();
+
+    // The name as written in the posts, rather than its slug
+    let tag_name = match &props.tag {
+        Some(tag) => match tagged
+            .iter()
+            .flat_map(|post| &post.tags)
+            .find(|name| slugify(name) == *tag)
+        {
+            Some(name) => Some(name.clone()),
+            None => return html! { <NotFoundView /> },
+        },
+        None => None,
+    };
+
+    let style = css!(
+        r#"
+        .blog-view {
+            max-width: 900px;
+            margin: 0 auto;
+            padding: 40px 20px;
+        }
+
+        .back-link {
+            display: inline-flex;
+            align-items: center;
+            gap: 8px;
+            color: var(--color-text-muted);
+            text-decoration: none;
+            font-size: 0.875rem;
+            margin-bottom: 30px;
+            transition: color 0.2s;
+        }
+
+        .back-link:hover {
+            color: var(--color-link);
+        }
+
+        .blog-tags {
+            justify-content: center;
+            margin-bottom: 30px;
+        }
+
+        .tag-link {
+            text-decoration: none;
+        }
+
+        .tag-count {
+            margin-left: 6px;
+            opacity: 0.6;
+        }
+
+        .post-list {
+            display: grid;
+            gap: 24px;
+        }
+
+        .post-title-link {
+            text-decoration: none;
+        }
+
+        .post-title {
+            font-size: 1.5rem;
+            font-weight: 600;
+            color: var(--color-heading);
+            margin: 0 0 8px;
+            transition: color 0.2s;
+        }
+
+        .post-title-link:hover .post-title {
+            color: var(--color-link);
+        }
+
+        .post-summary {
+            color: var(--color-text-secondary);
+            line-height: 1.7;
+            margin: 16px 0 0;
+        }
+
+        .no-posts {
+            text-align: center;
+            color: var(--color-text-muted);
+            padding: 60px 20px;
+        }
+        "#
+    );
+
+    html! {
+        <div class={style}>
+            <div class="blog-view">
+                if tag_name.is_some() {
+                    <Link<Route> to={Route::Blog} classes="back-link">
+                        <i class="fas fa-arrow-left"></i>
+                        {"All posts"}
+                    </Link<Route>>
+                }
+
+                if let Some(tag) = &tag_name {
+                    <PageHeader
+                        title={format!("Posts tagged “{tag}”")}
+                        subtitle={post_count(tagged.len())}
+                    />
+                } else {
+                    <PageHeader title="Blog" subtitle="Notes on Rust, networking and the things I build" />
+                    <TagList class="blog-tags">
+                        { for Post::tags(&posts).into_iter().map(|(tag, count)| html! {
+                            <Link<Route> to={Route::Tag { tag: slugify(tag) }} classes="tag-link">
+                                <Tag>
+                                    {tag}
+                                    <span class="tag-count">{count}</span>
+                                </Tag>
+                            </Link<Route>>
+                        }) }
+                    </TagList>
+                }
+
+                if tagged.is_empty() {
+                    <div class="no-posts">
+                        <p>{"No posts yet."}</p>
+                    </div>
+                } else {
+                    <div class="post-list">
+                        { for tagged.into_iter().map(render_post) }
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
+
+fn post_count(count: usize) -> String {
+    format!("{count} post{}", if count == 1 { "" } else { "s" })
+}
+
+fn render_post(post: &Post) -> Html {
+    html! {
+        <Card class="post-card" hoverable=true>
+            <Link<Route> to={Route::Post { slug: post.slug.clone() }} classes="post-title-link">
+                <h2 class="post-title">{&post.title}</h2>
+            </Link<Route>>
+            <PostMeta date={post.date} reading_minutes={post.reading_minutes} />
+            <p class="post-summary">{&post.summary}</p>
+            <PostTags tags={post.tags.clone()} />
+        </Card>
+    }
+}
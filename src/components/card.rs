@@ -0,0 +1,64 @@
+use crate::theme;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct CardProps {
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or_default]
+    pub class: Classes,
+    /// Lift the card under the pointer, for cards that lead somewhere.
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Leave out the padding so content like an image reaches the edges.
+    #[prop_or_default]
+    pub flush: bool,
+}
+
+/// The frosted glass panel the views lay their content out on.
+#[styled_component(Card)]
+pub fn card(props: &CardProps) -> Html {
+    let style = css!(
+        r#"
+        background: rgba(var(--color-overlay-rgb), 0.05);
+        backdrop-filter: blur(10px);
+        border-radius: var(--radius-lg);
+        padding: var(--space-xl);
+        box-shadow: 0 8px 32px rgba(var(--color-shadow-rgb), 0.2);
+        border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+
+        &.flush {
+            padding: 0;
+            overflow: hidden;
+        }
+
+        &.hoverable {
+            transition: all 0.3s ease;
+        }
+
+        &.hoverable:hover {
+            transform: translateY(-5px);
+            box-shadow: 0 20px 40px rgba(var(--color-shadow-rgb), 0.3);
+            border-color: rgba(var(--color-link-rgb), 0.3);
+        }
+
+        @media ${mobile} {
+            padding: var(--space-lg);
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    let classes = classes!(
+        style,
+        props.flush.then_some("flush"),
+        props.hoverable.then_some("hoverable"),
+        props.class.clone(),
+    );
+    html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    }
+}
@@ -0,0 +1,74 @@
+use super::Card;
+use crate::theme;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ConfigErrorViewProps {
+    pub message: AttrValue,
+}
+
+#[styled_component(ConfigErrorView)]
+pub fn config_error_view(props: &ConfigErrorViewProps) -> Html {
+    let style = css!(
+        r#"
+        .config-error-view {
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            min-height: 100vh;
+            padding: 40px 20px;
+            text-align: center;
+        }
+
+        .error-container {
+            max-width: 600px;
+            padding: 60px 40px;
+        }
+
+        .error-icon {
+            font-size: 4rem;
+            color: var(--color-warning);
+            margin-bottom: 24px;
+        }
+
+        .error-title {
+            font-size: 2rem;
+            color: var(--color-heading);
+            margin-bottom: 16px;
+            font-weight: 600;
+        }
+
+        .error-message {
+            font-size: 1.125rem;
+            color: var(--color-text-muted);
+            line-height: 1.6;
+        }
+
+        @media ${mobile} {
+            .error-container {
+                padding: 40px 24px;
+            }
+
+            .error-title {
+                font-size: 1.5rem;
+            }
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    html! {
+        <div class={style}>
+            <div class="config-error-view">
+                <Card class="error-container">
+                    <div class="error-icon">
+                        <i class="fas fa-tools"></i>
+                    </div>
+                    <h1 class="error-title">{"Site Misconfigured"}</h1>
+                    <p class="error-message">{&props.message}</p>
+                </Card>
+            </div>
+        </div>
+    }
+}
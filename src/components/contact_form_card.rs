@@ -0,0 +1,434 @@
+use super::Card;
+use crate::data::ContactForm;
+use crate::theme;
+use std::time::Duration;
+use stylist::yew::styled_component;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum SubmissionState {
+    Idle,
+    Submitting,
+    Success,
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ContactFormCardProps {
+    /// Endpoint the form posts to.
+    pub url: AttrValue,
+}
+
+#[styled_component(ContactFormCard)]
+pub fn contact_form_card(props: &ContactFormCardProps) -> Html {
+    let form_data = use_state(ContactForm::default);
+    let submission_state = use_state(|| SubmissionState::Idle);
+
+    let on_name_change = {
+        let form_data = form_data.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_form = (*form_data).clone();
+            new_form.name = input.value();
+            form_data.set(new_form);
+        })
+    };
+
+    let on_email_change = {
+        let form_data = form_data.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_form = (*form_data).clone();
+            new_form.email = input.value();
+            form_data.set(new_form);
+        })
+    };
+
+    let on_phone_change = {
+        let form_data = form_data.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_form = (*form_data).clone();
+            new_form.phone = input.value();
+            form_data.set(new_form);
+        })
+    };
+
+    let on_message_change = {
+        let form_data = form_data.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_form = (*form_data).clone();
+            new_form.message = input.value();
+            form_data.set(new_form);
+        })
+    };
+
+    let on_website_change = {
+        let form_data = form_data.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut new_form = (*form_data).clone();
+            new_form.website = input.value();
+            form_data.set(new_form);
+        })
+    };
+
+    let on_submit = {
+        let form_data = form_data.clone();
+        let submission_state = submission_state.clone();
+        let contact_url = props.url.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            
+            // Don't submit if already submitting
+            if matches!(*submission_state, SubmissionState::Submitting) {
+                return;
+            }
+
+            let contact_url = contact_url.clone();
+            let form_data = form_data.clone();
+            let submission_state = submission_state.clone();
+            
+            submission_state.set(SubmissionState::Submitting);
+            
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = reqwest::Client::new();
+                let request = client
+                    .post(contact_url.as_str())
+                    .json(&*form_data)
+                    .timeout(Duration::from_secs(10));
+                
+                match request.send().await {
+                    Ok(response) => {
+                        if response.status().is_success() {
+                            submission_state.set(SubmissionState::Success);
+                            form_data.set(ContactForm::default());
+                            
+                            // Reset to idle after 3 seconds
+                            let submission_state = submission_state.clone();
+                            gloo::timers::callback::Timeout::new(3000, move || {
+                                submission_state.set(SubmissionState::Idle);
+                            }).forget();
+                        } else {
+                            let status = response.status();
+                            let error_msg = match response.json::<serde_json::Value>().await {
+                                // The contact api explains what went wrong in `message`
+                                Ok(json) => match json.get("message").and_then(|message| message.as_str()) {
+                                    Some(message) => message.to_string(),
+                                    None => {
+                                        let str = serde_json::to_string(&json).unwrap();
+                                        format!("Server error: {status} | {str}", )
+                                    }
+                                },
+                                Err(_) => {
+                                    format!("Server error: {status}")
+                                }
+                            };
+                            submission_state.set(SubmissionState::Error(error_msg));
+                        }
+                    }
+                    Err(err) => {
+                        let error_msg = if err.is_timeout() {
+                            "Request timed out. Please try again.".to_string()
+                        } else if err.is_request() {
+                            "Unable to send request. Please check your connection or try again later.".to_string()
+                        } else {
+                            "Network error. Please try again later.".to_string()
+                        };
+                        submission_state.set(SubmissionState::Error(error_msg));
+                    }
+                }
+            });
+        })
+    };
+
+    let style = css!(
+        r#"
+        .form-grid {
+            display: grid;
+            grid-template-columns: 1fr 1fr;
+            gap: 24px;
+            margin-bottom: 0;
+        }
+
+        .form-group {
+            display: flex;
+            flex-direction: column;
+            margin-bottom: 24px;
+        }
+
+        .form-group:last-of-type {
+            margin-bottom: 0;
+        }
+
+        .form-label {
+            font-size: 0.875rem;
+            font-weight: 500;
+            color: var(--color-text);
+            margin-bottom: 8px;
+            letter-spacing: 0.025em;
+        }
+
+        .form-input,
+        .form-textarea {
+            background: rgba(var(--color-overlay-rgb), 0.08);
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.15);
+            border-radius: 8px;
+            padding: 12px 16px;
+            color: var(--color-heading);
+            font-size: 1rem;
+            transition: all 0.2s;
+            font-family: inherit;
+        }
+
+        .form-input:focus,
+        .form-textarea:focus {
+            outline: none;
+            background: rgba(var(--color-overlay-rgb), 0.1);
+            border-color: var(--color-link);
+            box-shadow: 0 0 0 3px rgba(var(--color-link-rgb), 0.1);
+        }
+
+        .form-input::placeholder,
+        .form-textarea::placeholder {
+            color: var(--color-text-subtle);
+        }
+
+        .form-textarea {
+            min-height: 150px;
+            resize: vertical;
+        }
+
+        .submit-btn {
+            width: 100%;
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
+            color: white;
+            border: none;
+            border-radius: 8px;
+            padding: 14px 24px;
+            font-size: 1rem;
+            font-weight: 600;
+            cursor: pointer;
+            transition: all 0.2s;
+            margin-top: 32px;
+            letter-spacing: 0.025em;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            gap: 12px;
+            min-height: 52px;
+        }
+
+        .submit-btn:hover:not(:disabled) {
+            transform: translateY(-2px);
+            box-shadow: 0 8px 20px rgba(var(--color-primary-rgb), 0.4);
+        }
+
+        .submit-btn:active {
+            transform: translateY(0);
+        }
+
+        .submit-btn:disabled {
+            opacity: 0.7;
+            cursor: not-allowed;
+            transform: none;
+        }
+
+        .submit-btn.success {
+            background: linear-gradient(45deg, var(--color-success), var(--color-success-text));
+        }
+
+        .submit-btn.error {
+            background: linear-gradient(45deg, var(--color-danger), rgba(var(--color-danger-rgb), 0.75));
+        }
+
+        .spinner {
+            width: 20px;
+            height: 20px;
+            border: 2px solid transparent;
+            border-top: 2px solid currentColor;
+            border-radius: 50%;
+            animation: spin 1s linear infinite;
+        }
+
+        @keyframes spin {
+            to {
+                transform: rotate(360deg);
+            }
+        }
+
+        .success-message {
+            background: rgba(var(--color-success-rgb), 0.1);
+            border: 1px solid rgba(var(--color-success-rgb), 0.3);
+            color: var(--color-success-text);
+            padding: 12px 16px;
+            border-radius: 8px;
+            margin-top: 16px;
+            text-align: center;
+            font-size: 0.875rem;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            gap: 8px;
+        }
+
+        .error-message {
+            background: rgba(var(--color-danger-rgb), 0.1);
+            border: 1px solid rgba(var(--color-danger-rgb), 0.3);
+            color: var(--color-danger);
+            padding: 12px 16px;
+            border-radius: 8px;
+            margin-top: 16px;
+            text-align: center;
+            font-size: 0.875rem;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            gap: 8px;
+        }
+
+        .form-trap {
+            position: absolute;
+            left: -10000px;
+            width: 1px;
+            height: 1px;
+            overflow: hidden;
+        }
+
+        @media ${mobile} {
+            .form-grid {
+                grid-template-columns: 1fr;
+            }
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    html! {
+        <div class={style}>
+            <Card>
+                <form onsubmit={on_submit}>
+                    <div class="form-grid">
+                        <div class="form-group">
+                            <label class="form-label" for="name">{"Name *"}</label>
+                            <input
+                                id="name"
+                                type="text"
+                                class="form-input"
+                                placeholder="John Doe"
+                                value={form_data.name.clone()}
+                                onchange={on_name_change}
+                                required=true
+                            />
+                        </div>
+
+                        <div class="form-group">
+                            <label class="form-label" for="email">{"Email *"}</label>
+                            <input
+                                id="email"
+                                type="email"
+                                class="form-input"
+                                placeholder="john.doe@example.com"
+                                value={form_data.email.clone()}
+                                onchange={on_email_change}
+                                required=true
+                            />
+                        </div>
+                    </div>
+
+                    <div class="form-group">
+                        <label class="form-label" for="phone">{"Phone Number"}</label>
+                        <input
+                            id="phone"
+                            type="tel"
+                            class="form-input"
+                            placeholder="+1 (555) 123-4567"
+                            value={form_data.phone.clone()}
+                            onchange={on_phone_change}
+                        />
+                    </div>
+
+                    <div class="form-group">
+                        <label class="form-label" for="message">{"Message *"}</label>
+                        <textarea
+                            id="message"
+                            class="form-textarea"
+                            placeholder="Tell me about your project or inquiry..."
+                            value={form_data.message.clone()}
+                            onchange={on_message_change}
+                            required=true
+                        />
+                    </div>
+
+                    // Left empty by people, who never see it; bots filling every field get rejected
+                    <div class="form-trap" aria-hidden="true">
+                        <label for="website">{"Website"}</label>
+                        <input
+                            id="website"
+                            type="text"
+                            tabindex="-1"
+                            autocomplete="off"
+                            value={form_data.website.clone()}
+                            onchange={on_website_change}
+                        />
+                    </div>
+
+                    <button 
+                        type="submit" 
+                        class={match *submission_state {
+                            SubmissionState::Success => "submit-btn success",
+                            SubmissionState::Error(_) => "submit-btn error",
+                            _ => "submit-btn"
+                        }}
+                        disabled={matches!(*submission_state, SubmissionState::Submitting)}
+                    >
+                        {match &*submission_state {
+                            SubmissionState::Idle => html! {
+                                <>
+                                    <i class="fas fa-paper-plane"></i>
+                                    {"Send Message"}
+                                </>
+                            },
+                            SubmissionState::Submitting => html! {
+                                <>
+                                    <div class="spinner"></div>
+                                    {"Sending..."}
+                                </>
+                            },
+                            SubmissionState::Success => html! {
+                                <>
+                                    <i class="fas fa-check"></i>
+                                    {"Message Sent!"}
+                                </>
+                            },
+                            SubmissionState::Error(_) => html! {
+                                <>
+                                    <i class="fas fa-exclamation-triangle"></i>
+                                    {"Try Again"}
+                                </>
+                            }
+                        }}
+                    </button>
+
+                    {match &*submission_state {
+                        SubmissionState::Success => html! {
+                            <div class="success-message">
+                                <i class="fas fa-check-circle"></i>
+                                {"Thank you for your message! I'll get back to you soon."}
+                            </div>
+                        },
+                        SubmissionState::Error(msg) => html! {
+                            <div class="error-message">
+                                <i class="fas fa-exclamation-circle"></i>
+                                {msg}
+                            </div>
+                        },
+                        _ => html! {}
+                    }}
+                </form>
+            </Card>
+        </div>
+    }
+}
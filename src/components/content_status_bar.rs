@@ -0,0 +1,113 @@
+use crate::content::{use_content, ContentStatus};
+use crate::theme;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+/// Shows that remote content is loading, or that it failed and an older copy is on screen.
+#[styled_component(ContentStatusBar)]
+pub fn content_status_bar() -> Html {
+    let status = use_content().status;
+    let dismissed = use_state(|| false);
+
+    let style = css!(
+        r#"
+        .content-loading {
+            position: fixed;
+            top: 0;
+            left: 0;
+            right: 0;
+            height: 3px;
+            z-index: 1100;
+            overflow: hidden;
+            background: rgba(var(--color-primary-rgb), 0.15);
+        }
+
+        .content-loading::after {
+            content: "";
+            position: absolute;
+            inset: 0;
+            width: 40%;
+            background: linear-gradient(90deg, var(--color-primary), var(--color-secondary));
+            animation: content-loading 1.2s ease-in-out infinite;
+        }
+
+        @keyframes content-loading {
+            from {
+                transform: translateX(-100%);
+            }
+            to {
+                transform: translateX(250%);
+            }
+        }
+
+        .content-error {
+            position: fixed;
+            bottom: 20px;
+            right: 20px;
+            z-index: 1100;
+            display: flex;
+            align-items: center;
+            gap: 12px;
+            max-width: 420px;
+            padding: 12px 16px;
+            background: rgba(var(--color-toast-rgb), 0.95);
+            border: 1px solid rgba(var(--color-warning-rgb), 0.4);
+            border-radius: 10px;
+            box-shadow: 0 8px 32px rgba(var(--color-shadow-rgb), 0.3);
+            color: var(--color-text-secondary);
+            font-size: 0.875rem;
+            line-height: 1.5;
+        }
+
+        .content-error i {
+            color: var(--color-warning);
+        }
+
+        .content-error button {
+            background: none;
+            border: none;
+            color: var(--color-text-muted);
+            cursor: pointer;
+            padding: 4px;
+        }
+
+        .content-error button:hover {
+            color: var(--color-heading);
+        }
+
+        @media ${mobile} {
+            .content-error {
+                left: 20px;
+            }
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    let on_dismiss = {
+        let dismissed = dismissed.clone();
+        Callback::from(move |_: MouseEvent| dismissed.set(true))
+    };
+
+    let bar = match status {
+        ContentStatus::Loading => html! {
+            <div class="content-loading" role="progressbar" aria-label="Loading the latest content"></div>
+        },
+        ContentStatus::Failed(reason) if !*dismissed => html! {
+            <div class="content-error" role="status" title={reason}>
+                <i class="fas fa-exclamation-triangle"></i>
+                <span>{"The latest content could not be loaded, so a saved copy is shown."}</span>
+                <button aria-label="Dismiss" onclick={on_dismiss}>
+                    <i class="fas fa-times"></i>
+                </button>
+            </div>
+        },
+        _ => return Html::default(),
+    };
+
+    html! {
+        <div class={style}>
+            {bar}
+        </div>
+    }
+}
@@ -0,0 +1,153 @@
+use super::ResponsiveImage;
+use crate::theme;
+use gloo::events::EventListener;
+use stylist::yew::styled_component;
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LightboxProps {
+    pub images: Vec<AttrValue>,
+    pub alt: AttrValue,
+    /// Image shown first.
+    #[prop_or_default]
+    pub start: usize,
+    pub on_close: Callback<()>,
+}
+
+/// Full screen gallery, navigable with the arrow keys and closed with escape.
+#[styled_component(Lightbox)]
+pub fn lightbox(props: &LightboxProps) -> Html {
+    let count = props.images.len();
+    let index = use_state(|| props.start.min(count.saturating_sub(1)));
+
+    let show = {
+        let index = index.clone();
+        Callback::from(move |offset: isize| {
+            if count > 0 {
+                index.set((*index as isize + offset).rem_euclid(count as isize) as usize);
+            }
+        })
+    };
+
+    // Registered again whenever the index changes, as `show` only sees the index it was made with
+    {
+        let show = show.clone();
+        let on_close = props.on_close.clone();
+        use_effect_with(*index, move |_| {
+            let listener = EventListener::new(&gloo::utils::document(), "keydown", move |event| {
+                let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                    return;
+                };
+                match event.key().as_str() {
+                    "Escape" => on_close.emit(()),
+                    "ArrowLeft" => show.emit(-1),
+                    "ArrowRight" => show.emit(1),
+                    _ => {}
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    let style = css!(
+        r#"
+        position: fixed;
+        inset: 0;
+        z-index: 1000;
+        background: rgba(0, 0, 0, 0.9);
+        display: flex;
+        align-items: center;
+        justify-content: center;
+        padding: 60px;
+
+        .lightbox-image {
+            max-width: min(1200px, 100%);
+            max-height: 100%;
+            width: 100%;
+        }
+
+        .lightbox-btn {
+            position: absolute;
+            background: rgba(255, 255, 255, 0.1);
+            border: 1px solid rgba(255, 255, 255, 0.2);
+            border-radius: 50%;
+            width: 44px;
+            height: 44px;
+            color: white;
+            font-size: 1.125rem;
+            cursor: pointer;
+            transition: background 0.2s;
+        }
+
+        .lightbox-btn:hover {
+            background: rgba(255, 255, 255, 0.2);
+        }
+
+        .lightbox-close {
+            top: 16px;
+            right: 16px;
+        }
+
+        .lightbox-prev {
+            left: 16px;
+        }
+
+        .lightbox-next {
+            right: 16px;
+        }
+
+        .lightbox-counter {
+            position: absolute;
+            bottom: 16px;
+            color: #d1d5db;
+            font-size: 0.875rem;
+        }
+
+        @media ${mobile} {
+            padding: 60px 10px;
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    let on_backdrop = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+    let stop = Callback::from(|e: MouseEvent| e.stop_propagation());
+    let step = |offset: isize| {
+        let show = show.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            show.emit(offset);
+        })
+    };
+
+    let Some(src) = props.images.get(*index) else {
+        return Html::default();
+    };
+
+    html! {
+        <div class={style} role="dialog" aria-modal="true" aria-label={props.alt.clone()} onclick={on_backdrop.clone()}>
+            <button class="lightbox-btn lightbox-close" aria-label="Close" onclick={on_backdrop}>
+                <i class="fas fa-times"></i>
+            </button>
+            if count > 1 {
+                <button class="lightbox-btn lightbox-prev" aria-label="Previous image" onclick={step(-1)}>
+                    <i class="fas fa-chevron-left"></i>
+                </button>
+            }
+            <div class="lightbox-image" onclick={stop}>
+                <ResponsiveImage src={src.clone()} alt={props.alt.clone()} />
+            </div>
+            if count > 1 {
+                <button class="lightbox-btn lightbox-next" aria-label="Next image" onclick={step(1)}>
+                    <i class="fas fa-chevron-right"></i>
+                </button>
+                <div class="lightbox-counter">{format!("{} / {count}", *index + 1)}</div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,67 @@
+use crate::markdown;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct MarkdownTextProps {
+    pub source: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A short piece of markdown from the datafiles, such as a description, taking on the font and
+/// color of its container.
+#[styled_component(MarkdownText)]
+pub fn markdown_text(props: &MarkdownTextProps) -> Html {
+    let html = use_memo(props.source.clone(), |source| markdown::render(source));
+
+    let style = css!(
+        r#"
+        & > :first-child {
+            margin-top: 0;
+        }
+
+        & > :last-child {
+            margin-bottom: 0;
+        }
+
+        p,
+        ul,
+        ol,
+        blockquote {
+            margin: 0 0 0.75em;
+        }
+
+        ul,
+        ol {
+            padding-left: 1.5em;
+        }
+
+        li + li {
+            margin-top: 0.25em;
+        }
+
+        a {
+            color: var(--color-link);
+        }
+
+        code {
+            font-size: 0.9em;
+            background: rgba(var(--color-overlay-rgb), 0.08);
+            padding: 2px 6px;
+            border-radius: var(--radius-sm);
+        }
+
+        blockquote {
+            padding-left: 1em;
+            border-left: 3px solid rgba(var(--color-link-rgb), 0.4);
+        }
+        "#
+    );
+
+    html! {
+        <div class={classes!(style, props.class.clone())}>
+            {(*html).clone()}
+        </div>
+    }
+}
@@ -1,17 +1,61 @@
 mod about_view;
+mod blog_view;
+mod card;
+mod config_error_view;
+#[cfg(feature = "contact-form")]
+mod contact_form_card;
 mod contact_view;
+mod content_status_bar;
+mod lightbox;
+mod markdown_text;
 mod not_found_view;
+mod page_header;
+mod post_meta;
+mod post_view;
+#[cfg(feature = "legal-pages")]
 mod privacy_view;
+mod profile_avatar;
+mod project_detail_view;
+mod project_preview;
 mod projects_view;
+mod qr_code;
+mod responsive_image;
 mod resume_view;
+mod save_contact_card;
+mod section_header;
 mod sidebar;
+mod tag;
+#[cfg(feature = "legal-pages")]
 mod terms_view;
+mod theme_toggle;
 
 pub use about_view::AboutView;
+pub use blog_view::BlogView;
+pub use card::Card;
+pub use config_error_view::ConfigErrorView;
+#[cfg(feature = "contact-form")]
+pub use contact_form_card::ContactFormCard;
 pub use contact_view::ContactView;
+pub use content_status_bar::ContentStatusBar;
+pub use lightbox::Lightbox;
+pub use markdown_text::MarkdownText;
 pub use not_found_view::NotFoundView;
+pub use page_header::PageHeader;
+pub use post_meta::{PostMeta, PostTags};
+pub use post_view::PostView;
+#[cfg(feature = "legal-pages")]
 pub use privacy_view::PrivacyView;
+pub use profile_avatar::ProfileAvatar;
+pub use project_detail_view::ProjectDetailView;
+pub use project_preview::ProjectPreview;
 pub use projects_view::ProjectsView;
+pub use qr_code::QrCode;
+pub use responsive_image::ResponsiveImage;
 pub use resume_view::ResumeView;
+pub use save_contact_card::SaveContactCard;
+pub use section_header::{SectionHeader, Tone};
 pub use sidebar::Sidebar;
+pub use tag::{Tag, TagList};
+#[cfg(feature = "legal-pages")]
 pub use terms_view::TermsView;
+pub use theme_toggle::ThemeToggle;
@@ -1,5 +1,6 @@
-use crate::Route;
-use stylist::{yew::styled_component, Style};
+use super::Card;
+use crate::{theme, Route};
+use stylist::yew::styled_component;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -15,7 +16,7 @@ pub fn not_found_view() -> Html {
         })
     };
 
-    let style = Style::new(css!(
+    let style = css!(
         r#"
         .not-found-view {
             display: flex;
@@ -30,7 +31,7 @@ pub fn not_found_view() -> Html {
         .error-code {
             font-size: 8rem;
             font-weight: 700;
-            background: linear-gradient(45deg, #3b82f6, #8b5cf6);
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
             -webkit-background-clip: text;
             -webkit-text-fill-color: transparent;
             background-clip: text;
@@ -40,33 +41,28 @@ pub fn not_found_view() -> Html {
 
         .error-title {
             font-size: 2.5rem;
-            color: white;
+            color: var(--color-heading);
             margin-bottom: 16px;
             font-weight: 600;
         }
 
         .error-message {
             font-size: 1.25rem;
-            color: #9ca3af;
+            color: var(--color-text-muted);
             margin-bottom: 40px;
             max-width: 500px;
             line-height: 1.6;
         }
 
         .error-container {
-            background: rgba(255, 255, 255, 0.05);
-            backdrop-filter: blur(10px);
-            border-radius: 16px;
             padding: 60px 40px;
-            box-shadow: 0 8px 32px rgba(0, 0, 0, 0.2);
-            border: 1px solid rgba(255, 255, 255, 0.1);
         }
 
         .home-button {
             display: inline-flex;
             align-items: center;
             gap: 12px;
-            background: linear-gradient(45deg, #3b82f6, #8b5cf6);
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
             color: white;
             border: none;
             border-radius: 8px;
@@ -81,7 +77,7 @@ pub fn not_found_view() -> Html {
 
         .home-button:hover {
             transform: translateY(-2px);
-            box-shadow: 0 8px 20px rgba(59, 130, 246, 0.4);
+            box-shadow: 0 8px 20px rgba(var(--color-primary-rgb), 0.4);
         }
 
         .home-button:active {
@@ -115,7 +111,7 @@ pub fn not_found_view() -> Html {
             }
         }
 
-        @media (max-width: 768px) {
+        @media ${mobile} {
             .error-code {
                 font-size: 6rem;
             }
@@ -132,14 +128,14 @@ pub fn not_found_view() -> Html {
                 padding: 40px 24px;
             }
         }
-        "#
-    ))
-    .expect("Failed to create style");
+        "#,
+        mobile = theme::mobile(),
+    );
 
     html! {
         <div class={style}>
             <div class="not-found-view">
-                <div class="error-container">
+                <Card class="error-container">
                     <div class="error-code glitch">{"404"}</div>
                     <h1 class="error-title">{"Page Not Found"}</h1>
                     <p class="error-message">
@@ -149,7 +145,7 @@ pub fn not_found_view() -> Html {
                         <i class="fas fa-home"></i>
                         {"Back to Home"}
                     </button>
-                </div>
+                </Card>
             </div>
         </div>
     }
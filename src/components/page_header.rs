@@ -0,0 +1,75 @@
+use crate::theme;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PageHeaderProps {
+    pub title: AttrValue,
+    #[prop_or_default]
+    pub subtitle: Option<AttrValue>,
+    /// Shown under the subtitle, such as actions or tags.
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or(true)]
+    pub centered: bool,
+    /// Separate the header from what follows with a line, for headers inside a card.
+    #[prop_or_default]
+    pub divided: bool,
+}
+
+/// The gradient title every page opens with.
+#[styled_component(PageHeader)]
+pub fn page_header(props: &PageHeaderProps) -> Html {
+    let style = css!(
+        r#"
+        margin-bottom: var(--space-xl);
+
+        &.centered {
+            text-align: center;
+        }
+
+        &.divided {
+            padding-bottom: 30px;
+            border-bottom: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+        }
+
+        h1 {
+            font-size: 2.5rem;
+            font-weight: 700;
+            margin-bottom: 10px;
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
+            -webkit-background-clip: text;
+            -webkit-text-fill-color: transparent;
+            background-clip: text;
+        }
+
+        .page-header-subtitle {
+            font-size: 1.125rem;
+            color: var(--color-text-muted);
+            font-weight: 300;
+        }
+
+        @media ${mobile} {
+            h1 {
+                font-size: 2rem;
+            }
+        }
+        "#,
+        mobile = theme::mobile(),
+    );
+
+    let classes = classes!(
+        style,
+        props.centered.then_some("centered"),
+        props.divided.then_some("divided"),
+    );
+    html! {
+        <header class={classes}>
+            <h1>{props.title.clone()}</h1>
+            if let Some(subtitle) = &props.subtitle {
+                <p class="page-header-subtitle">{subtitle.clone()}</p>
+            }
+            {props.children.clone()}
+        </header>
+    }
+}
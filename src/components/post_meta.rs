@@ -0,0 +1,72 @@
+use super::{Tag, TagList};
+use crate::data::{slugify, Date};
+use crate::Route;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PostMetaProps {
+    pub date: Date,
+    pub reading_minutes: u32,
+}
+
+/// Publication date and reading time of a post.
+#[styled_component(PostMeta)]
+pub fn post_meta(props: &PostMetaProps) -> Html {
+    let style = css!(
+        r#"
+        display: flex;
+        flex-wrap: wrap;
+        gap: 16px;
+        color: var(--color-text-muted);
+        font-size: 0.875rem;
+
+        i {
+            margin-right: 6px;
+        }
+        "#
+    );
+
+    html! {
+        <div class={style}>
+            <time datetime={props.date.to_string()}>
+                <i class="far fa-calendar"></i>
+                {props.date.localized()}
+            </time>
+            <span>
+                <i class="far fa-clock"></i>
+                {format!("{} min read", props.reading_minutes)}
+            </span>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PostTagsProps {
+    pub tags: Vec<String>,
+}
+
+/// The tags of a post, each linking to the other posts with it.
+#[styled_component(PostTags)]
+pub fn post_tags(props: &PostTagsProps) -> Html {
+    let style = css!(
+        r#"
+        margin-top: 16px;
+
+        a {
+            text-decoration: none;
+        }
+        "#
+    );
+
+    html! {
+        <TagList class={style}>
+            { for props.tags.iter().map(|tag| html! {
+                <Link<Route> to={Route::Tag { tag: slugify(tag) }}>
+                    <Tag>{tag}</Tag>
+                </Link<Route>>
+            }) }
+        </TagList>
+    }
+}
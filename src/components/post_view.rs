@@ -0,0 +1,201 @@
+use super::{Card, NotFoundView, PageHeader, PostMeta, PostTags};
+use crate::content::use_posts;
+use crate::data::Post;
+use crate::{markdown, Route};
+use stylist::yew::{styled_component, Global};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PostViewProps {
+    pub slug: String,
+}
+
+#[styled_component(PostView)]
+pub fn post_view(props: &PostViewProps) -> Html {
+    let posts = use_posts();
+    // Newest first, so the previous post is the next one in the list
+    let Some(index) = posts.iter().position(|post| post.slug == props.slug) else {
+        return html! { <NotFoundView /> };
+    };
+    let post = &posts[index];
+    let previous = posts.get(index + 1);
+    let next = index.checked_sub(1).map(|index| &posts[index]);
+
+    let style = css!(
+        r#"
+        .post-view {
+            max-width: 900px;
+            margin: 0 auto;
+            padding: 40px 20px;
+        }
+
+        .back-link {
+            display: inline-flex;
+            align-items: center;
+            gap: 8px;
+            color: var(--color-text-muted);
+            text-decoration: none;
+            font-size: 0.875rem;
+            margin-bottom: 30px;
+            transition: color 0.2s;
+        }
+
+        .back-link:hover {
+            color: var(--color-link);
+        }
+
+        .post-body {
+            color: var(--color-text-secondary);
+            line-height: 1.8;
+            margin-bottom: 30px;
+        }
+
+        .post-body > :first-child {
+            margin-top: 0;
+        }
+
+        .post-body h1,
+        .post-body h2,
+        .post-body h3 {
+            color: var(--color-heading);
+            margin: 1.5em 0 0.5em;
+        }
+
+        .post-body p,
+        .post-body ul,
+        .post-body ol,
+        .post-body blockquote {
+            margin: 0 0 1em;
+        }
+
+        .post-body ul,
+        .post-body ol {
+            padding-left: 1.5em;
+        }
+
+        .post-body blockquote {
+            padding-left: 1em;
+            border-left: 3px solid rgba(var(--color-link-rgb), 0.5);
+            color: var(--color-text-muted);
+        }
+
+        .post-body a {
+            color: var(--color-link);
+        }
+
+        .post-body code {
+            background: rgba(var(--color-overlay-rgb), 0.08);
+            border-radius: 4px;
+            padding: 2px 6px;
+            font-size: 0.9em;
+        }
+
+        .post-body pre {
+            border-radius: var(--radius-sm);
+            padding: var(--space-md);
+            margin: 0 0 1em;
+            overflow-x: auto;
+            line-height: 1.5;
+        }
+
+        .post-body pre code {
+            background: none;
+            padding: 0;
+        }
+
+        .post-body table {
+            border-collapse: collapse;
+            margin: 0 0 1em;
+        }
+
+        .post-body th,
+        .post-body td {
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+            padding: 6px 12px;
+        }
+
+        .post-nav {
+            display: grid;
+            grid-template-columns: 1fr 1fr;
+            gap: 16px;
+        }
+
+        .post-nav-link {
+            display: flex;
+            flex-direction: column;
+            gap: 6px;
+            padding: 20px;
+            background: rgba(var(--color-overlay-rgb), 0.03);
+            border-radius: var(--radius-md);
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.08);
+            text-decoration: none;
+            transition: all 0.2s;
+        }
+
+        .post-nav-link:hover {
+            background: rgba(var(--color-overlay-rgb), 0.06);
+            border-color: rgba(var(--color-link-rgb), 0.3);
+            transform: translateY(-2px);
+        }
+
+        .post-nav-link.next {
+            grid-column: 2;
+            text-align: right;
+        }
+
+        .post-nav-label {
+            color: var(--color-text-muted);
+            font-size: 0.75rem;
+            text-transform: uppercase;
+            letter-spacing: 0.05em;
+        }
+
+        .post-nav-title {
+            color: var(--color-heading);
+            font-weight: 600;
+        }
+        "#
+    );
+
+    html! {
+        <div class={style}>
+            <Global css={markdown::HIGHLIGHT_CSS} />
+            <article class="post-view">
+                <Link<Route> to={Route::Blog} classes="back-link">
+                    <i class="fas fa-arrow-left"></i>
+                    {"All posts"}
+                </Link<Route>>
+
+                <PageHeader title={post.title.clone()} centered=false>
+                    <PostMeta date={post.date} reading_minutes={post.reading_minutes} />
+                    <PostTags tags={post.tags.clone()} />
+                </PageHeader>
+
+                <Card class="post-body">
+                    {markdown::rendered(&post.html)}
+                </Card>
+
+                if previous.is_some() || next.is_some() {
+                    <nav class="post-nav" aria-label="More posts">
+                        if let Some(previous) = previous {
+                            {render_neighbour(previous, "previous", "Previous")}
+                        }
+                        if let Some(next) = next {
+                            {render_neighbour(next, "next", "Next")}
+                        }
+                    </nav>
+                }
+            </article>
+        </div>
+    }
+}
+
+fn render_neighbour(post: &Post, class: &'static str, label: &str) -> Html {
+    html! {
+        <Link<Route> to={Route::Post { slug: post.slug.clone() }} classes={classes!("post-nav-link", class)}>
+            <span class="post-nav-label">{label}</span>
+            <span class="post-nav-title">{&post.title}</span>
+        </Link<Route>>
+    }
+}
@@ -0,0 +1,47 @@
+use super::ResponsiveImage;
+use crate::content::use_profile;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+/// The profile's avatar, falling back to the initials of the name when there is none or it
+/// cannot be loaded.
+#[styled_component(ProfileAvatar)]
+pub fn profile_avatar() -> Html {
+    let profile = use_profile();
+    let failed = use_state(|| false);
+
+    // A new avatar from remote content deserves another try
+    {
+        let failed = failed.clone();
+        use_effect_with(profile.avatar.clone(), move |_| failed.set(false));
+    }
+
+    let style = css!(
+        r#"
+        overflow: hidden;
+        "#
+    );
+
+    let avatar = profile.avatar.as_ref().filter(|_| !*failed);
+    let onerror = {
+        let failed = failed.clone();
+        Callback::from(move |_| failed.set(true))
+    };
+
+    html! {
+        <div class={classes!("profile-avatar", style)}>
+            if let Some(avatar) = avatar {
+                <ResponsiveImage
+                    src={avatar.clone()}
+                    alt={profile.name.clone()}
+                    sizes="48px"
+                    cover=true
+                    img_class="u-photo"
+                    {onerror}
+                />
+            } else {
+                {profile.initials()}
+            }
+        </div>
+    }
+}
@@ -0,0 +1,337 @@
+use super::{Card, Lightbox, MarkdownText, NotFoundView, PageHeader, ResponsiveImage, SectionHeader, Tag, TagList};
+use crate::content::use_projects;
+use crate::data::Project;
+use crate::{markdown, theme, Route};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+const RELATED_PROJECTS: usize = 3;
+
+#[derive(Properties, PartialEq)]
+pub struct ProjectDetailViewProps {
+    pub slug: String,
+}
+
+#[styled_component(ProjectDetailView)]
+pub fn project_detail_view(props: &ProjectDetailViewProps) -> Html {
+    let lightbox = use_state(|| None::<usize>);
+    let projects = use_projects();
+    let Some(project) = projects.iter().find(|project| project.slug() == props.slug) else {
+        return html! { <NotFoundView /> };
+    };
+    let related = project.related(&projects, RELATED_PROJECTS);
+    let images = project
+        .images()
+        .into_iter()
+        .map(AttrValue::from)
+        .collect::<Vec<_>>();
+
+    let open_image = |index: usize| {
+        let lightbox = lightbox.clone();
+        Callback::from(move |_: MouseEvent| lightbox.set(Some(index)))
+    };
+    let on_close = {
+        let lightbox = lightbox.clone();
+        Callback::from(move |_| lightbox.set(None))
+    };
+
+    let style = css!(
+        r#"
+        .project-detail {
+            max-width: 900px;
+            margin: 0 auto;
+            padding: 40px 20px;
+        }
+
+        .back-link {
+            display: inline-flex;
+            align-items: center;
+            gap: 8px;
+            color: var(--color-text-muted);
+            text-decoration: none;
+            font-size: 0.875rem;
+            margin-bottom: 30px;
+            transition: color 0.2s;
+        }
+
+        .back-link:hover {
+            color: var(--color-link);
+        }
+
+        .project-summary {
+            font-size: 1.125rem;
+            color: var(--color-text-secondary);
+            line-height: 1.7;
+            font-weight: 300;
+        }
+
+        .project-date {
+            color: var(--color-text-muted);
+            font-size: 0.875rem;
+            display: flex;
+            align-items: center;
+            gap: 6px;
+            margin-bottom: 16px;
+        }
+
+        .detail-card {
+            margin-bottom: 30px;
+        }
+
+        .project-facts {
+            display: grid;
+            grid-template-columns: repeat(auto-fit, minmax(250px, 1fr));
+            gap: 20px;
+        }
+
+        .project-fact h3 {
+            color: var(--color-text-muted);
+            font-size: 0.75rem;
+            text-transform: uppercase;
+            letter-spacing: 0.05em;
+            margin: 0 0 8px;
+        }
+
+        .project-fact p {
+            color: var(--color-text);
+            line-height: 1.6;
+            margin: 0;
+        }
+
+        .tech-tags {
+            margin: 20px 0;
+        }
+
+        .project-links {
+            display: flex;
+            gap: 16px;
+            flex-wrap: wrap;
+        }
+
+        .project-link {
+            display: flex;
+            align-items: center;
+            gap: 8px;
+            color: var(--color-link);
+            text-decoration: none;
+            font-weight: 500;
+            transition: all 0.2s;
+            padding: 8px 16px;
+            border: 1px solid rgba(var(--color-link-rgb), 0.3);
+            border-radius: 8px;
+            background: rgba(var(--color-link-rgb), 0.05);
+        }
+
+        .project-link:hover {
+            color: var(--color-heading);
+            background: rgba(var(--color-link-rgb), 0.15);
+            border-color: rgba(var(--color-link-rgb), 0.5);
+        }
+
+        .project-body {
+            color: var(--color-text-secondary);
+            line-height: 1.8;
+        }
+
+        .project-body h1,
+        .project-body h2,
+        .project-body h3 {
+            color: var(--color-heading);
+            margin: 1.5em 0 0.5em;
+        }
+
+        .project-body a {
+            color: var(--color-link);
+        }
+
+        .project-body code {
+            background: rgba(var(--color-overlay-rgb), 0.08);
+            border-radius: 4px;
+            padding: 2px 6px;
+            font-size: 0.9em;
+        }
+
+        .project-body pre {
+            background: rgba(var(--color-shadow-rgb), 0.3);
+            border-radius: 8px;
+            padding: 16px;
+            overflow-x: auto;
+        }
+
+        .project-body pre code {
+            background: none;
+            padding: 0;
+        }
+
+        .screenshots {
+            display: grid;
+            grid-template-columns: repeat(auto-fit, minmax(250px, 1fr));
+            gap: 16px;
+        }
+
+        .screenshot,
+        .project-cover {
+            border-radius: 8px;
+            overflow: hidden;
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+        }
+
+        .project-cover {
+            margin-bottom: 30px;
+        }
+
+        .related-grid {
+            display: grid;
+            grid-template-columns: repeat(auto-fit, minmax(200px, 1fr));
+            gap: 16px;
+        }
+
+        .related-card {
+            display: block;
+            padding: 20px;
+            background: rgba(var(--color-overlay-rgb), 0.03);
+            border-radius: 12px;
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.08);
+            text-decoration: none;
+            transition: all 0.2s;
+        }
+
+        .related-card:hover {
+            background: rgba(var(--color-overlay-rgb), 0.06);
+            border-color: rgba(var(--color-link-rgb), 0.3);
+            transform: translateY(-2px);
+        }
+
+        .related-name {
+            color: var(--color-heading);
+            font-weight: 600;
+            margin-bottom: 8px;
+        }
+
+        .related-description {
+            color: var(--color-text-muted);
+            font-size: 0.875rem;
+            line-height: 1.5;
+        }
+        "#
+    );
+
+    html! {
+        <div class={style}>
+            <div class="project-detail">
+                <Link<Route> to={Route::Projects} classes="back-link">
+                    <i class="fas fa-arrow-left"></i>
+                    {"All projects"}
+                </Link<Route>>
+
+                if let Some(image) = &project.image {
+                    <div class="project-cover">
+                        <ResponsiveImage
+                            src={image.clone()}
+                            alt={format!("Preview of {}", project.name)}
+                            sizes="(max-width: 900px) 100vw, 860px"
+                            onclick={open_image(0)}
+                        />
+                    </div>
+                }
+
+                <PageHeader title={project.name.clone()} centered=false>
+                    if let Some(date) = project.dates.localized() {
+                        <div class="project-date">
+                            <i class="far fa-calendar"></i>
+                            {date}
+                        </div>
+                    }
+                    if let Some(description) = &project.description {
+                        <MarkdownText class="project-summary" source={description.clone()} />
+                    }
+
+                    <TagList class="tech-tags">
+                        { for project.keywords.iter().map(|keyword| html! { <Tag>{keyword}</Tag> }) }
+                    </TagList>
+
+                    <div class="project-links">
+                        if let Some(website) = &project.website {
+                            <a href={website.clone()} target="_blank" rel="noopener noreferrer" class="project-link">
+                                <i class="fas fa-external-link-alt"></i>
+                                {"Website"}
+                            </a>
+                        }
+                        if let Some(github) = &project.github {
+                            <a href={github.clone()} target="_blank" rel="noopener noreferrer" class="project-link">
+                                <i class="fab fa-github"></i>
+                                {"Code"}
+                            </a>
+                        }
+                    </div>
+                </PageHeader>
+
+                if project.role.is_some() || project.outcome.is_some() {
+                    <Card class="detail-card project-facts">
+                        if let Some(role) = &project.role {
+                            <div class="project-fact">
+                                <h3>{"Role"}</h3>
+                                <p>{role}</p>
+                            </div>
+                        }
+                        if let Some(outcome) = &project.outcome {
+                            <div class="project-fact">
+                                <h3>{"Outcome"}</h3>
+                                <p>{outcome}</p>
+                            </div>
+                        }
+                    </Card>
+                }
+
+                if let Some(body) = &project.body {
+                    <Card class="detail-card project-body">
+                        {markdown::render(body)}
+                    </Card>
+                }
+
+                if !project.screenshots.is_empty() {
+                    <Card class="detail-card">
+                        <SectionHeader title="Screenshots" />
+                        <div class="screenshots">
+                            { for images.iter().enumerate().skip(usize::from(project.image.is_some())).map(|(index, screenshot)| html! {
+                                <div class="screenshot">
+                                    <ResponsiveImage
+                                        src={screenshot.clone()}
+                                        alt={format!("Screenshot of {}", project.name)}
+                                        sizes={format!("{} 100vw, 300px", theme::mobile())}
+                                        onclick={open_image(index)}
+                                    />
+                                </div>
+                            }) }
+                        </div>
+                    </Card>
+                }
+
+                if let Some(start) = *lightbox {
+                    <Lightbox images={images.clone()} alt={project.name.clone()} {start} {on_close} />
+                }
+
+                if !related.is_empty() {
+                    <Card class="detail-card">
+                        <SectionHeader title="Related Projects" />
+                        <div class="related-grid">
+                            { for related.into_iter().map(render_related) }
+                        </div>
+                    </Card>
+                }
+            </div>
+        </div>
+    }
+}
+
+fn render_related(project: &Project) -> Html {
+    html! {
+        <Link<Route> to={Route::ProjectDetail { slug: project.slug() }} classes="related-card">
+            <div class="related-name">{&project.name}</div>
+            if let Some(description) = &project.description {
+                <div class="related-description">{markdown::plain_text(description)}</div>
+            }
+        </Link<Route>>
+    }
+}
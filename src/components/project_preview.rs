@@ -0,0 +1,72 @@
+use super::{Lightbox, ResponsiveImage};
+use crate::theme;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ProjectPreviewProps {
+    pub images: Vec<AttrValue>,
+    pub name: AttrValue,
+}
+
+/// Cover image of a project card, opening every image of the project in a lightbox.
+#[styled_component(ProjectPreview)]
+pub fn project_preview(props: &ProjectPreviewProps) -> Html {
+    let open = use_state(|| false);
+
+    let style = css!(
+        r#"
+        position: relative;
+        height: 100%;
+
+        .image-count {
+            position: absolute;
+            right: 12px;
+            bottom: 12px;
+            display: flex;
+            align-items: center;
+            gap: 6px;
+            background: rgba(0, 0, 0, 0.6);
+            color: white;
+            font-size: 0.75rem;
+            padding: 4px 10px;
+            border-radius: 12px;
+            pointer-events: none;
+        }
+        "#
+    );
+
+    let Some(cover) = props.images.first() else {
+        return Html::default();
+    };
+
+    let on_open = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(true))
+    };
+    let on_close = {
+        let open = open.clone();
+        Callback::from(move |_| open.set(false))
+    };
+
+    html! {
+        <div class={style}>
+            <ResponsiveImage
+                src={cover.clone()}
+                alt={format!("Preview of {}", props.name)}
+                sizes={format!("{} 100vw, 400px", theme::mobile())}
+                cover=true
+                onclick={on_open}
+            />
+            if props.images.len() > 1 {
+                <span class="image-count">
+                    <i class="fas fa-images"></i>
+                    {props.images.len()}
+                </span>
+            }
+            if *open {
+                <Lightbox images={props.images.clone()} alt={props.name.clone()} {on_close} />
+            }
+        </div>
+    }
+}
@@ -1,12 +1,243 @@
-use crate::data::{get_projects, Project};
-use stylist::{yew::styled_component, Style};
+use super::{Card, MarkdownText, PageHeader, ProjectPreview, Tag, TagList};
+use crate::content::use_projects;
+use crate::data::Project;
+use crate::theme;
+use crate::Route;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use stylist::yew::styled_component;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum SortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    Name,
+    Featured,
+}
+
+impl SortOrder {
+    const ALL: [SortOrder; 4] = [
+        SortOrder::Newest,
+        SortOrder::Oldest,
+        SortOrder::Name,
+        SortOrder::Featured,
+    ];
+
+    /// Same as the serialized form used in the url.
+    fn value(self) -> &'static str {
+        match self {
+            SortOrder::Newest => "newest",
+            SortOrder::Oldest => "oldest",
+            SortOrder::Name => "name",
+            SortOrder::Featured => "featured",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortOrder::Newest => "Newest first",
+            SortOrder::Oldest => "Oldest first",
+            SortOrder::Name => "Name",
+            SortOrder::Featured => "Featured first",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum KeywordMatch {
+    #[default]
+    All,
+    Any,
+}
+
+/// Filter state of the page, kept in the url query so filtered views can be shared, e.g.
+/// `/projects?tags=Rust,Tokio&match=any&sort=name`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct ProjectQuery {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    q: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    tags: String,
+    #[serde(rename = "match", skip_serializing_if = "is_default")]
+    keyword_match: KeywordMatch,
+    #[serde(skip_serializing_if = "is_default")]
+    sort: SortOrder,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+impl ProjectQuery {
+    fn keywords(&self) -> Vec<&str> {
+        self.tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .collect()
+    }
+
+    fn is_selected(&self, keyword: &str) -> bool {
+        self.keywords()
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case(keyword))
+    }
+
+    fn toggle_keyword(&self, keyword: &str) -> Self {
+        let mut keywords = self.keywords();
+        if self.is_selected(keyword) {
+            keywords.retain(|tag| !tag.eq_ignore_ascii_case(keyword));
+        } else {
+            keywords.push(keyword);
+        }
+        Self {
+            tags: keywords.join(","),
+            ..self.clone()
+        }
+    }
+
+    fn is_filtering(&self) -> bool {
+        !self.q.trim().is_empty() || !self.keywords().is_empty()
+    }
+
+    fn matches(&self, project: &Project) -> bool {
+        let has_keyword = |tag: &&str| {
+            project
+                .keywords
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(tag))
+        };
+        let keywords = self.keywords();
+        let keywords_match = keywords.is_empty()
+            || match self.keyword_match {
+                KeywordMatch::All => keywords.iter().all(has_keyword),
+                KeywordMatch::Any => keywords.iter().any(has_keyword),
+            };
+
+        let search = self.q.trim().to_lowercase();
+        let search_matches = search.is_empty()
+            || project.name.to_lowercase().contains(&search)
+            || project
+                .description
+                .as_ref()
+                .is_some_and(|description| description.to_lowercase().contains(&search));
+
+        keywords_match && search_matches
+    }
+
+    /// Filters and orders `projects`. Newest and oldest order by the same date in opposite
+    /// directions, with undated projects last either way.
+    fn apply<'a>(&self, projects: &'a [Project]) -> Vec<&'a Project> {
+        let mut projects = projects
+            .iter()
+            .filter(|project| self.matches(project))
+            .collect::<Vec<_>>();
+        match self.sort {
+            SortOrder::Newest => projects.sort_by_key(|project| Reverse(project.dates.latest())),
+            SortOrder::Oldest => projects.sort_by_key(|project| {
+                let latest = project.dates.latest();
+                (latest.is_none(), latest)
+            }),
+            SortOrder::Name => projects.sort_by_key(|project| project.name.to_lowercase()),
+            SortOrder::Featured => projects.sort_by_key(|project| !project.featured),
+        }
+        projects
+    }
+}
+
+/// Every keyword in use, most common first.
+fn all_keywords(projects: &[Project]) -> Vec<String> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for keyword in projects.iter().flat_map(|project| &project.keywords) {
+        match counts.iter_mut().find(|(existing, _)| existing == keyword) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((keyword.clone(), 1)),
+        }
+    }
+    counts.sort_by_key(|(keyword, count)| (Reverse(*count), keyword.to_lowercase()));
+    counts.into_iter().map(|(keyword, _)| keyword).collect()
+}
 
 #[styled_component(ProjectsView)]
 pub fn projects_view() -> Html {
-    let projects = get_projects();
+    let navigator = use_navigator().expect("ProjectsView must be rendered inside a router");
+    let query = use_location()
+        .and_then(|location| location.query::<ProjectQuery>().ok())
+        .unwrap_or_default();
+
+    let all_projects = use_projects();
+    let keywords = all_keywords(&all_projects);
+    let projects = query.apply(&all_projects);
+
+    // Replacing keeps every keystroke of a search out of the back button history
+    let set_query = Callback::from(move |query: ProjectQuery| {
+        let _ = navigator.replace_with_query(&Route::Projects, &query);
+    });
+
+    let on_toggle_keyword = {
+        let query = query.clone();
+        let set_query = set_query.clone();
+        Callback::from(move |keyword: String| set_query.emit(query.toggle_keyword(&keyword)))
+    };
+
+    let on_search = {
+        let query = query.clone();
+        let set_query = set_query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            set_query.emit(ProjectQuery {
+                q: input.value(),
+                ..query.clone()
+            });
+        })
+    };
+
+    let on_sort = {
+        let query = query.clone();
+        let set_query = set_query.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let sort = SortOrder::ALL
+                .into_iter()
+                .find(|sort| sort.value() == select.value())
+                .unwrap_or_default();
+            set_query.emit(ProjectQuery {
+                sort,
+                ..query.clone()
+            });
+        })
+    };
 
-    let style = Style::new(css!(
+    let on_match = |keyword_match: KeywordMatch| {
+        let query = query.clone();
+        let set_query = set_query.clone();
+        Callback::from(move |_: MouseEvent| {
+            set_query.emit(ProjectQuery {
+                keyword_match,
+                ..query.clone()
+            });
+        })
+    };
+
+    let on_clear = {
+        let query = query.clone();
+        let set_query = set_query.clone();
+        Callback::from(move |_: MouseEvent| {
+            set_query.emit(ProjectQuery {
+                sort: query.sort,
+                ..ProjectQuery::default()
+            });
+        })
+    };
+
+    let style = css!(
         r#"
         .projects-view {
             max-width: 1200px;
@@ -14,26 +245,6 @@ pub fn projects_view() -> Html {
             padding: 40px 20px;
         }
 
-        .projects-header {
-            text-align: center;
-            margin-bottom: 40px;
-        }
-
-        .projects-header h1 {
-            font-size: 2.5rem;
-            margin-bottom: 10px;
-            background: linear-gradient(45deg, #3b82f6, #8b5cf6);
-            -webkit-background-clip: text;
-            -webkit-text-fill-color: transparent;
-            background-clip: text;
-        }
-
-        .projects-header p {
-            font-size: 1.125rem;
-            color: #9ca3af;
-            font-weight: 300;
-        }
-
         .projects-grid {
             display: grid;
             grid-template-columns: repeat(auto-fit, minmax(350px, 1fr));
@@ -41,31 +252,14 @@ pub fn projects_view() -> Html {
         }
 
         .project-card {
-            background: rgba(255, 255, 255, 0.05);
-            backdrop-filter: blur(10px);
-            border-radius: 16px;
-            overflow: hidden;
-            box-shadow: 0 8px 32px rgba(0, 0, 0, 0.2);
-            border: 1px solid rgba(255, 255, 255, 0.1);
-            transition: all 0.3s ease;
             display: flex;
             flex-direction: column;
         }
 
-        .project-card:hover {
-            transform: translateY(-5px);
-            box-shadow: 0 20px 40px rgba(0, 0, 0, 0.3);
-            border-color: rgba(96, 165, 250, 0.3);
-        }
-
         .project-image {
             height: 200px;
-            background: linear-gradient(135deg, #3b82f6, #8b5cf6);
-            display: flex;
-            align-items: center;
-            justify-content: center;
-            color: white;
-            font-size: 3rem;
+            background: linear-gradient(135deg, var(--color-primary), var(--color-secondary));
+            overflow: hidden;
         }
 
         .project-content {
@@ -79,12 +273,20 @@ pub fn projects_view() -> Html {
         .project-title {
             font-size: 1.5rem;
             font-weight: 600;
-            color: white;
+            color: var(--color-heading);
             margin: 0;
         }
 
+        .project-title-link {
+            text-decoration: none;
+        }
+
+        .project-title-link:hover .project-title {
+            color: var(--color-link);
+        }
+
         .project-description {
-            color: #d1d5db;
+            color: var(--color-text-secondary);
             line-height: 1.7;
             font-size: 1rem;
             margin: 0;
@@ -92,7 +294,7 @@ pub fn projects_view() -> Html {
         }
 
         .project-date {
-            color: #9ca3af;
+            color: var(--color-text-muted);
             font-size: 0.875rem;
             font-weight: 400;
             margin-bottom: 10px;
@@ -106,25 +308,115 @@ pub fn projects_view() -> Html {
         }
 
         .tech-tags {
+            margin: 10px 0;
+        }
+
+        .projects-toolbar {
             display: flex;
+            gap: 16px;
+            margin-bottom: 20px;
+        }
+
+        .search-box {
+            flex: 1;
+            position: relative;
+        }
+
+        .search-box i {
+            position: absolute;
+            left: 16px;
+            top: 50%;
+            transform: translateY(-50%);
+            color: var(--color-text-subtle);
+        }
+
+        .search-input,
+        .sort-select {
+            background: rgba(var(--color-overlay-rgb), 0.08);
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.15);
+            border-radius: 8px;
+            padding: 12px 16px;
+            color: var(--color-heading);
+            font-size: 1rem;
+            font-family: inherit;
+            transition: all 0.2s;
+        }
+
+        .search-input {
+            width: 100%;
+            box-sizing: border-box;
+            padding-left: 44px;
+        }
+
+        .search-input:focus,
+        .sort-select:focus {
+            outline: none;
+            border-color: var(--color-link);
+            box-shadow: 0 0 0 3px rgba(var(--color-link-rgb), 0.1);
+        }
+
+        .sort-select option {
+            background: var(--color-surface);
+        }
+
+        .keyword-filter {
+            margin-bottom: 20px;
+        }
+
+        .filter-status {
+            display: flex;
+            align-items: center;
             flex-wrap: wrap;
-            gap: 10px;
-            margin: 10px 0;
+            gap: 16px;
+            color: var(--color-text-muted);
+            font-size: 0.875rem;
+            margin-bottom: 30px;
+        }
+
+        .match-toggle {
+            display: flex;
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.15);
+            border-radius: 8px;
+            overflow: hidden;
         }
 
-        .tech-tag {
-            background: rgba(59, 130, 246, 0.1);
-            border: 1px solid rgba(59, 130, 246, 0.3);
-            color: #93c5fd;
-            padding: 6px 14px;
-            border-radius: 20px;
+        .match-btn,
+        .clear-btn {
+            background: none;
+            border: none;
+            color: var(--color-text-secondary);
+            padding: 6px 12px;
             font-size: 0.875rem;
+            font-family: inherit;
+            cursor: pointer;
             transition: all 0.2s;
         }
 
-        .tech-tag:hover {
-            background: rgba(59, 130, 246, 0.2);
-            border-color: rgba(59, 130, 246, 0.5);
+        .match-btn.active {
+            background: rgba(var(--color-link-rgb), 0.2);
+            color: var(--color-heading);
+        }
+
+        .clear-btn {
+            display: flex;
+            align-items: center;
+            gap: 6px;
+            color: var(--color-link);
+        }
+
+        .clear-btn:hover {
+            color: var(--color-heading);
+        }
+
+        .no-results {
+            text-align: center;
+            color: var(--color-text-muted);
+            padding: 60px 20px;
+        }
+
+        .no-results i {
+            font-size: 2.5rem;
+            margin-bottom: 16px;
         }
 
         .project-links {
@@ -132,27 +424,27 @@ pub fn projects_view() -> Html {
             gap: 16px;
             margin-top: auto;
             padding-top: 20px;
-            border-top: 1px solid rgba(255, 255, 255, 0.1);
+            border-top: 1px solid rgba(var(--color-overlay-rgb), 0.1);
         }
 
         .project-link {
             display: flex;
             align-items: center;
             gap: 8px;
-            color: #60a5fa;
+            color: var(--color-link);
             text-decoration: none;
             font-weight: 500;
             transition: all 0.2s;
             padding: 8px 16px;
-            border: 1px solid rgba(96, 165, 250, 0.3);
+            border: 1px solid rgba(var(--color-link-rgb), 0.3);
             border-radius: 8px;
-            background: rgba(96, 165, 250, 0.05);
+            background: rgba(var(--color-link-rgb), 0.05);
         }
 
         .project-link:hover {
-            color: white;
-            background: rgba(96, 165, 250, 0.15);
-            border-color: rgba(96, 165, 250, 0.5);
+            color: var(--color-heading);
+            background: rgba(var(--color-link-rgb), 0.15);
+            border-color: rgba(var(--color-link-rgb), 0.5);
             transform: translateY(-2px);
         }
 
@@ -160,66 +452,144 @@ pub fn projects_view() -> Html {
             font-size: 1rem;
         }
 
-        @media (max-width: 768px) {
+        @media ${mobile} {
+            .projects-toolbar {
+                flex-direction: column;
+            }
+
             .projects-grid {
                 grid-template-columns: 1fr;
                 gap: 24px;
             }
 
-            .projects-header h1 {
-                font-size: 2rem;
-            }
-
             .project-content {
                 padding: 24px;
             }
         }
-        "#
-    ))
-    .expect("Failed to create style");
+        "#,
+        mobile = theme::mobile(),
+    );
 
     html! {
         <div class={style}>
             <div class="projects-view">
-                <div class="projects-header">
-                    <h1>{"Projects"}</h1>
-                    <p>{"A showcase of my work and contributions"}</p>
-                </div>
+                <PageHeader title="Projects" subtitle="A showcase of my work and contributions" />
 
-                <div class="projects-grid">
-                    { for projects.iter().map(render_project) }
+                <div class="projects-toolbar">
+                    <div class="search-box">
+                        <i class="fas fa-search"></i>
+                        <input
+                            type="search"
+                            class="search-input"
+                            placeholder="Search projects..."
+                            aria-label="Search projects"
+                            value={query.q.clone()}
+                            oninput={on_search}
+                        />
+                    </div>
+                    <select class="sort-select" aria-label="Sort projects" onchange={on_sort}>
+                        { for SortOrder::ALL.into_iter().map(|sort| html! {
+                            <option value={sort.value()} selected={sort == query.sort}>
+                                {sort.label()}
+                            </option>
+                        }) }
+                    </select>
                 </div>
+
+                <TagList class="keyword-filter">
+                    { for keywords.iter().map(|keyword| render_keyword(keyword, &query, &on_toggle_keyword)) }
+                </TagList>
+
+                if query.is_filtering() {
+                    <div class="filter-status">
+                        <span>
+                            {format!(
+                                "Showing {} project{}",
+                                projects.len(),
+                                if projects.len() == 1 { "" } else { "s" }
+                            )}
+                        </span>
+                        if query.keywords().len() > 1 {
+                            <div class="match-toggle">
+                                <button
+                                    class={classes!("match-btn", (query.keyword_match == KeywordMatch::All).then_some("active"))}
+                                    onclick={on_match(KeywordMatch::All)}
+                                >
+                                    {"Match all"}
+                                </button>
+                                <button
+                                    class={classes!("match-btn", (query.keyword_match == KeywordMatch::Any).then_some("active"))}
+                                    onclick={on_match(KeywordMatch::Any)}
+                                >
+                                    {"Match any"}
+                                </button>
+                            </div>
+                        }
+                        <button class="clear-btn" onclick={on_clear}>
+                            <i class="fas fa-times"></i>
+                            {"Clear filters"}
+                        </button>
+                    </div>
+                }
+
+                if projects.is_empty() {
+                    <div class="no-results">
+                        <i class="fas fa-folder-open"></i>
+                        <p>{"No projects match the current filters."}</p>
+                    </div>
+                } else {
+                    <div class="projects-grid">
+                        { for projects.iter().map(|project| render_project(project, &query, &on_toggle_keyword)) }
+                    </div>
+                }
             </div>
         </div>
     }
 }
 
-fn render_project(project: &Project) -> Html {
+fn render_keyword(keyword: &str, query: &ProjectQuery, on_toggle: &Callback<String>) -> Html {
+    let onclick = {
+        let keyword = keyword.to_string();
+        on_toggle.reform(move |_: MouseEvent| keyword.clone())
+    };
+    html! {
+        <Tag active={query.is_selected(keyword)} {onclick}>{keyword}</Tag>
+    }
+}
+
+fn render_project(project: &Project, query: &ProjectQuery, on_toggle: &Callback<String>) -> Html {
     html! {
-        <div class="project-card">
-            if let Some(_image) = &project.image {
+        <Card class="project-card" hoverable=true flush=true>
+            if project.image.is_some() {
                 <div class="project-image">
-                    // TODO: Add section for image previews
-                    <i class="fas fa-code"></i>
+                    <ProjectPreview
+                        images={project.images().into_iter().map(AttrValue::from).collect::<Vec<_>>()}
+                        name={project.name.clone()}
+                    />
                 </div>
             }
             <div class="project-content">
-                <h3 class="project-title">{&project.name}</h3>
-                if let Some(date) = &project.date {
+                <Link<Route> to={Route::ProjectDetail { slug: project.slug() }} classes="project-title-link">
+                    <h3 class="project-title">{&project.name}</h3>
+                </Link<Route>>
+                if let Some(date) = project.dates.localized() {
                     <div class="project-date">
                         <i class="far fa-calendar"></i>
                         {date}
                     </div>
                 }
-                <p class="project-description">{&project.description}</p>
+                <MarkdownText class="project-description" source={project.description.clone().unwrap_or_default()} />
 
-                <div class="tech-tags">
-                    { for project.keywords.iter().map(|keyword| {
-                        html! { <span class="tech-tag">{keyword}</span> }
-                    }) }
-                </div>
+                <TagList class="tech-tags">
+                    { for project.keywords.iter().map(|keyword| render_keyword(keyword, query, on_toggle)) }
+                </TagList>
 
                 <div class="project-links">
+                    <Link<Route> to={Route::ProjectDetail { slug: project.slug() }} classes="project-link">
+                        <i class="fas fa-book-open"></i>
+                        {"Details"}
+                    </Link<Route>>
+
                     if let Some(website) = &project.website {
                         <a
                             href={website.clone()}
@@ -245,6 +615,6 @@ fn render_project(project: &Project) -> Html {
                     }
                 </div>
             </div>
-        </div>
+        </Card>
     }
 }
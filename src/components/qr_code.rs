@@ -0,0 +1,62 @@
+use qrcode::{Color, EcLevel};
+use std::fmt::Write;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+// Light modules around the code, which scanners need to find its edges
+const QUIET_ZONE: usize = 4;
+
+#[derive(Properties, PartialEq)]
+pub struct QrCodeProps {
+    /// Text to encode, such as a url or a vCard.
+    pub data: AttrValue,
+    /// What scanning the code does, for screen readers.
+    pub label: AttrValue,
+}
+
+/// Outline of the dark modules as an svg path, along with the size of the code including its
+/// quiet zone. `None` when the data is too long to fit in a QR code.
+fn modules(data: &str) -> Option<(usize, String)> {
+    let code = qrcode::QrCode::with_error_correction_level(data, EcLevel::M).ok()?;
+    let width = code.width();
+    let mut path = String::new();
+    for (index, color) in code.to_colors().into_iter().enumerate() {
+        if color == Color::Dark {
+            let (x, y) = (index % width + QUIET_ZONE, index / width + QUIET_ZONE);
+            write!(path, "M{x} {y}h1v1h-1z").unwrap();
+        }
+    }
+    Some((width + 2 * QUIET_ZONE, path))
+}
+
+/// A QR code drawn in the page, dark on light whatever the theme so phones can read it.
+#[styled_component(QrCode)]
+pub fn qr_code(props: &QrCodeProps) -> Html {
+    let modules = use_memo(props.data.clone(), |data| modules(data));
+
+    let style = css!(
+        r#"
+        display: block;
+        width: 100%;
+        max-width: 240px;
+        height: auto;
+        border-radius: var(--radius-sm);
+        "#
+    );
+
+    let Some((size, path)) = modules.as_ref() else {
+        return Html::default();
+    };
+    html! {
+        <svg
+            class={style}
+            viewBox={format!("0 0 {size} {size}")}
+            role="img"
+            aria-label={props.label.clone()}
+            shape-rendering="crispEdges"
+        >
+            <rect width={size.to_string()} height={size.to_string()} fill="#ffffff" />
+            <path d={path.clone()} fill="#000000" />
+        </svg>
+    }
+}
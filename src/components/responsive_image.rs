@@ -0,0 +1,130 @@
+use crate::images::{self, VARIANT_FORMATS};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ResponsiveImageProps {
+    /// Path under `assets/images`, or a full url for images hosted elsewhere.
+    pub src: AttrValue,
+    pub alt: AttrValue,
+    /// Rendered width of the image, used by the browser to pick a variant.
+    #[prop_or(AttrValue::Static("100vw"))]
+    pub sizes: AttrValue,
+    /// Cover the container instead of keeping the image's own aspect ratio.
+    #[prop_or_default]
+    pub cover: bool,
+    /// Classes for the `<img>` itself, such as the `u-photo` of a microformat.
+    #[prop_or_default]
+    pub img_class: Classes,
+    #[prop_or_default]
+    pub onclick: Option<Callback<MouseEvent>>,
+    /// Called when the image cannot be loaded, including before the app took over the page.
+    #[prop_or_default]
+    pub onerror: Option<Callback<()>>,
+}
+
+#[styled_component(ResponsiveImage)]
+pub fn responsive_image(props: &ResponsiveImageProps) -> Html {
+    let img_ref = use_node_ref();
+
+    // A prerendered image may have failed before hydration attached the error listener
+    {
+        let img_ref = img_ref.clone();
+        let onerror = props.onerror.clone();
+        use_effect_with(props.src.clone(), move |_| {
+            let img = img_ref.cast::<web_sys::HtmlImageElement>();
+            if let (Some(img), Some(onerror)) = (img, onerror) {
+                if img.complete() && img.natural_width() == 0 {
+                    onerror.emit(());
+                }
+            }
+        });
+    }
+
+    // The blurred placeholder sits behind the image, which paints over it once loaded
+    let style = css!(
+        r#"
+        position: relative;
+        overflow: hidden;
+        display: block;
+        width: 100%;
+
+        &.cover {
+            height: 100%;
+        }
+
+        &.clickable {
+            cursor: zoom-in;
+        }
+
+        &::before {
+            content: "";
+            position: absolute;
+            inset: 0;
+            background-image: var(--placeholder);
+            background-size: cover;
+            background-position: center;
+            filter: blur(12px);
+            transform: scale(1.1);
+        }
+
+        img {
+            position: relative;
+            display: block;
+            width: 100%;
+            height: auto;
+        }
+
+        &.cover img {
+            height: 100%;
+            object-fit: cover;
+        }
+        "#
+    );
+
+    let classes = classes!(
+        style,
+        props.cover.then_some("cover"),
+        props.onclick.is_some().then_some("clickable"),
+    );
+    let onclick = props.onclick.clone();
+    let onerror = props.onerror.clone().map(|onerror| onerror.reform(|_: Event| ()));
+
+    // Images hosted elsewhere or missing from the manifest are shown as they are
+    let Some(image) = images::find(&props.src) else {
+        return html! {
+            <div class={classes} {onclick}>
+                <img
+                    ref={img_ref}
+                    class={props.img_class.clone()}
+                    src={images::public_url(&props.src)}
+                    alt={props.alt.clone()}
+                    loading="lazy"
+                    decoding="async"
+                    {onerror}
+                />
+            </div>
+        };
+    };
+
+    html! {
+        <div class={classes} style={format!("--placeholder: url({})", image.placeholder)} {onclick}>
+            <picture>
+                { for VARIANT_FORMATS.into_iter().map(|(extension, mime_type)| html! {
+                    <source type={mime_type} srcset={image.srcset(extension)} sizes={props.sizes.clone()} />
+                }) }
+                <img
+                    ref={img_ref}
+                    class={props.img_class.clone()}
+                    src={image.url()}
+                    alt={props.alt.clone()}
+                    width={image.width.to_string()}
+                    height={image.height.to_string()}
+                    loading="lazy"
+                    decoding="async"
+                    {onerror}
+                />
+            </picture>
+        </div>
+    }
+}
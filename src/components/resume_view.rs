@@ -1,12 +1,25 @@
-use crate::data::{get_resume, Education, Experience, SkillCategory};
-use stylist::{yew::styled_component, Style};
+use super::{Card, MarkdownText, PageHeader, SectionHeader, Tag, TagList, Tone};
+use crate::content::{use_profile, use_resume};
+use crate::data::{DateEnd, Dates, Education, Experience, SkillCategory, YearMonth};
+use crate::export::{self, pdf};
+use crate::theme;
+use stylist::yew::styled_component;
 use yew::prelude::*;
 
 #[styled_component(ResumeView)]
 pub fn resume_view() -> Html {
-    let resume = get_resume();
-
-    let style = Style::new(css!(
+    let profile = use_profile();
+    let resume = use_resume();
+
+    let on_download = {
+        let resume = resume.clone();
+        Callback::from(move |_: MouseEvent| {
+            let bytes = pdf::render_resume(&profile, &resume);
+            export::download(&pdf::file_name(&profile), pdf::MIME_TYPE, &bytes);
+        })
+    };
+
+    let style = css!(
         r#"
         .resume-view {
             max-width: 1000px;
@@ -14,24 +27,26 @@ pub fn resume_view() -> Html {
             padding: 40px 20px;
         }
 
-        .resume-header {
-            text-align: center;
-            margin-bottom: 40px;
-        }
-
-        .resume-header h1 {
-            font-size: 2.5rem;
-            margin-bottom: 10px;
-            background: linear-gradient(45deg, #3b82f6, #8b5cf6);
-            -webkit-background-clip: text;
-            -webkit-text-fill-color: transparent;
-            background-clip: text;
+        .download-btn {
+            display: inline-flex;
+            align-items: center;
+            gap: 10px;
+            margin-top: 20px;
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
+            color: white;
+            border: none;
+            border-radius: 8px;
+            padding: 12px 24px;
+            font-size: 1rem;
+            font-weight: 600;
+            cursor: pointer;
+            transition: all 0.2s;
+            letter-spacing: 0.025em;
         }
 
-        .resume-header p {
-            font-size: 1.125rem;
-            color: #9ca3af;
-            font-weight: 300;
+        .download-btn:hover {
+            transform: translateY(-2px);
+            box-shadow: 0 8px 20px rgba(var(--color-primary-rgb), 0.4);
         }
 
         .resume-sections {
@@ -39,59 +54,11 @@ pub fn resume_view() -> Html {
             gap: 30px;
         }
 
-        .resume-section {
-            background: rgba(255, 255, 255, 0.05);
-            backdrop-filter: blur(10px);
-            border-radius: 16px;
-            padding: 32px;
-            box-shadow: 0 8px 32px rgba(0, 0, 0, 0.2);
-            border: 1px solid rgba(255, 255, 255, 0.1);
-        }
-
-        .section-header {
-            display: flex;
-            align-items: center;
-            gap: 16px;
-            margin-bottom: 28px;
-            padding-bottom: 16px;
-            border-bottom: 1px solid rgba(255, 255, 255, 0.1);
-        }
-
-        .section-icon {
-            width: 48px;
-            height: 48px;
-            border-radius: 12px;
-            display: flex;
-            align-items: center;
-            justify-content: center;
-            font-size: 1.5rem;
-            color: white;
-        }
-
-        .section-icon.blue {
-            background: linear-gradient(135deg, #3b82f6, #60a5fa);
-        }
-
-        .section-icon.green {
-            background: linear-gradient(135deg, #10b981, #34d399);
-        }
-
-        .section-icon.purple {
-            background: linear-gradient(135deg, #8b5cf6, #a78bfa);
-        }
-
-        .section-title {
-            font-size: 1.75rem;
-            font-weight: 600;
-            color: white;
-            margin: 0;
-        }
-
         .experience-item,
         .education-item {
             margin-bottom: 28px;
             padding-bottom: 28px;
-            border-bottom: 1px solid rgba(255, 255, 255, 0.05);
+            border-bottom: 1px solid rgba(var(--color-overlay-rgb), 0.05);
         }
 
         .experience-item:last-child,
@@ -113,54 +80,36 @@ pub fn resume_view() -> Html {
         .item-title {
             font-size: 1.25rem;
             font-weight: 600;
-            color: white;
+            color: var(--color-heading);
             margin: 0;
         }
 
         .item-duration {
             font-size: 0.875rem;
-            color: #9ca3af;
-            background: rgba(255, 255, 255, 0.05);
+            color: var(--color-text-muted);
+            background: rgba(var(--color-overlay-rgb), 0.05);
             padding: 4px 12px;
             border-radius: 20px;
-            border: 1px solid rgba(255, 255, 255, 0.1);
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+        }
+
+        .item-tenure {
+            color: var(--color-text-subtle);
         }
 
         .item-company {
-            color: #60a5fa;
+            color: var(--color-link);
             font-weight: 500;
             margin-bottom: 12px;
             font-size: 1.125rem;
         }
 
         .item-description {
-            color: #d1d5db;
+            color: var(--color-text-secondary);
             line-height: 1.7;
             margin-bottom: 16px;
         }
 
-        .tech-tags {
-            display: flex;
-            flex-wrap: wrap;
-            gap: 10px;
-        }
-
-        .tech-tag {
-            background: rgba(59, 130, 246, 0.1);
-            border: 1px solid rgba(59, 130, 246, 0.3);
-            color: #93c5fd;
-            padding: 6px 14px;
-            border-radius: 20px;
-            font-size: 0.875rem;
-            transition: all 0.2s;
-        }
-
-        .tech-tag:hover {
-            background: rgba(59, 130, 246, 0.2);
-            border-color: rgba(59, 130, 246, 0.5);
-            transform: translateY(-2px);
-        }
-
         .skills-grid {
             display: grid;
             grid-template-columns: repeat(auto-fit, minmax(250px, 1fr));
@@ -170,19 +119,11 @@ pub fn resume_view() -> Html {
         .skill-category h3 {
             font-size: 1.125rem;
             font-weight: 600;
-            color: #a78bfa;
+            color: var(--color-accent);
             margin-bottom: 16px;
         }
 
-        @media (max-width: 768px) {
-            .resume-header h1 {
-                font-size: 2rem;
-            }
-
-            .resume-section {
-                padding: 24px;
-            }
-
+        @media ${mobile} {
             .item-header {
                 flex-direction: column;
                 align-items: flex-start;
@@ -192,95 +133,105 @@ pub fn resume_view() -> Html {
                 grid-template-columns: 1fr;
             }
         }
-        "#
-    ))
-    .expect("Failed to create style");
+        "#,
+        mobile = theme::mobile(),
+    );
 
     html! {
         <div class={style}>
-            <div class="resume-view">
-                <div class="resume-header">
-                    <h1>{"Resume"}</h1>
-                    <p>{"Professional experience and qualifications"}</p>
-                </div>
+            <div class="resume-view h-resume">
+                <PageHeader title="Resume" subtitle="Professional experience and qualifications">
+                    <button class="download-btn" onclick={on_download}>
+                        <i class="fas fa-file-pdf"></i>
+                        {"Download PDF"}
+                    </button>
+                </PageHeader>
 
                 <div class="resume-sections">
                     // Experience Section
-                    <div class="resume-section">
-                        <div class="section-header">
-                            <div class="section-icon blue">
-                                <i class="fas fa-briefcase"></i>
-                            </div>
-                            <h2 class="section-title">{"Experience"}</h2>
-                        </div>
+                    <Card>
+                        <SectionHeader title="Experience" icon="fas fa-briefcase" tone={Tone::Blue} />
 
                         <div class="experiences">
-                            { for resume.experience.iter().map(|exp| render_experience(exp)) }
+                            { for resume.experience.iter().map(render_experience) }
                         </div>
-                    </div>
+                    </Card>
 
                     // Education Section
-                    <div class="resume-section">
-                        <div class="section-header">
-                            <div class="section-icon green">
-                                <i class="fas fa-graduation-cap"></i>
-                            </div>
-                            <h2 class="section-title">{"Education"}</h2>
-                        </div>
+                    <Card>
+                        <SectionHeader title="Education" icon="fas fa-graduation-cap" tone={Tone::Green} />
 
                         <div class="education-items">
-                            { for resume.education.iter().map(|edu| render_education(edu)) }
+                            { for resume.education.iter().map(render_education) }
                         </div>
-                    </div>
+                    </Card>
 
                     // Skills Section
-                    <div class="resume-section">
-                        <div class="section-header">
-                            <div class="section-icon purple">
-                                <i class="fas fa-cog"></i>
-                            </div>
-                            <h2 class="section-title">{"Skills"}</h2>
-                        </div>
+                    <Card>
+                        <SectionHeader title="Skills" icon="fas fa-cog" tone={Tone::Purple} />
 
                         <div class="skills-grid">
-                            { for resume.skills.iter().map(|category| render_skill_category(category)) }
+                            { for resume.skills.iter().map(render_skill_category) }
                         </div>
-                    </div>
+                    </Card>
                 </div>
             </div>
         </div>
     }
 }
 
+/// Machine readable dates for the `h-event` of an entry, alongside the localized ones.
+fn event_dates(dates: &Dates) -> Html {
+    html! {
+        <>
+            if let Some(start) = dates.start {
+                <data class="dt-start" value={start.to_string()}></data>
+            }
+            if let Some(DateEnd::On(end)) = dates.end {
+                <data class="dt-end" value={end.to_string()}></data>
+            }
+        </>
+    }
+}
+
 fn render_experience(exp: &Experience) -> Html {
     html! {
-        <div class="experience-item">
+        <div class="experience-item p-experience h-event">
             <div class="item-header">
-                <h3 class="item-title">{&exp.position}</h3>
-                <span class="item-duration">{&exp.duration}</span>
-            </div>
-            <p class="item-company">{&exp.company}</p>
-            <p class="item-description">{&exp.description}</p>
-            <div class="tech-tags">
-                { for exp.technologies.iter().map(|tech| {
-                    html! { <span class="tech-tag">{tech}</span> }
-                }) }
+                <h3 class="item-title p-name">{&exp.position}</h3>
+                if let Some(dates) = exp.dates.localized() {
+                    <span class="item-duration">
+                        {dates}
+                        if let Some(tenure) = exp.dates.tenure(YearMonth::now()) {
+                            <span class="item-tenure">{format!(" · {tenure}")}</span>
+                        }
+                        {event_dates(&exp.dates)}
+                    </span>
+                }
             </div>
+            <p class="item-company p-location h-card">{&exp.company}</p>
+            <MarkdownText class="item-description p-summary" source={exp.description.clone().unwrap_or_default()} />
+            <TagList>
+                { for exp.technologies.iter().map(|tech| html! { <Tag class="p-category">{tech}</Tag> }) }
+            </TagList>
         </div>
     }
 }
 
 fn render_education(edu: &Education) -> Html {
     html! {
-        <div class="education-item">
+        <div class="education-item p-education h-event">
             <div class="item-header">
-                <h3 class="item-title">{&edu.degree}</h3>
-                if let Some(year) = edu.year.as_ref() {
-                    <span class="item-duration">{year}</span>
+                <h3 class="item-title p-name">{&edu.degree}</h3>
+                if let Some(dates) = edu.dates.localized() {
+                    <span class="item-duration">
+                        {dates}
+                        {event_dates(&edu.dates)}
+                    </span>
                 }
             </div>
-            <p class="item-company">{&edu.school}</p>
-            <p class="item-description">{&edu.details}</p>
+            <p class="item-company p-location h-card">{&edu.school}</p>
+            <p class="item-description p-summary">{&edu.details}</p>
         </div>
     }
 }
@@ -289,11 +240,9 @@ fn render_skill_category(category: &SkillCategory) -> Html {
     html! {
         <div class="skill-category">
             <h3>{&category.name}</h3>
-            <div class="tech-tags">
-                { for category.items.iter().map(|skill| {
-                    html! { <span class="tech-tag">{skill}</span> }
-                }) }
-            </div>
+            <TagList>
+                { for category.items.iter().map(|skill| html! { <Tag class="p-skill">{skill}</Tag> }) }
+            </TagList>
         </div>
     }
 }
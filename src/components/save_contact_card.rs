@@ -0,0 +1,105 @@
+use super::{Card, QrCode, SectionHeader, Tag, TagList, Tone};
+use crate::content::use_profile;
+use crate::export::{self, vcard};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+/// What the QR code opens when scanned.
+#[derive(Clone, Copy, PartialEq)]
+enum QrTarget {
+    Contact,
+    Website,
+}
+
+/// The profile as a vCard to download, and as a QR code for saving it from someone else's screen.
+#[styled_component(SaveContactCard)]
+pub fn save_contact_card() -> Html {
+    let profile = use_profile();
+    let target = use_state(|| QrTarget::Contact);
+
+    let card = use_memo(profile.clone(), |profile| vcard::render(profile));
+    let on_download = {
+        let profile = profile.clone();
+        let card = card.clone();
+        Callback::from(move |_: MouseEvent| {
+            export::download(
+                &vcard::file_name(&profile),
+                vcard::MIME_TYPE,
+                card.as_bytes(),
+            );
+        })
+    };
+    let select = |selected: QrTarget| {
+        let target = target.clone();
+        Callback::from(move |_: MouseEvent| target.set(selected))
+    };
+
+    let style = css!(
+        r#"
+        .save-contact {
+            display: flex;
+            flex-direction: column;
+            align-items: center;
+            gap: 20px;
+            text-align: center;
+        }
+
+        .save-contact-caption {
+            color: var(--color-text-muted);
+            font-size: 0.95rem;
+        }
+
+        .vcard-btn {
+            display: inline-flex;
+            align-items: center;
+            gap: 10px;
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
+            color: white;
+            border: none;
+            border-radius: 8px;
+            padding: 12px 24px;
+            font-size: 1rem;
+            font-weight: 600;
+            cursor: pointer;
+            transition: all 0.2s;
+        }
+
+        .vcard-btn:hover {
+            transform: translateY(-2px);
+            box-shadow: 0 8px 20px rgba(var(--color-primary-rgb), 0.4);
+        }
+        "#
+    );
+
+    let (data, caption) = match (*target, &profile.website) {
+        (QrTarget::Website, Some(website)) => (website.clone(), format!("Scan to open {website}")),
+        _ => (
+            card.to_string(),
+            format!("Scan to add {} to your contacts", profile.name),
+        ),
+    };
+
+    html! {
+        <Card class={style}>
+            <SectionHeader title="Save my contact" icon="fas fa-address-card" tone={Tone::Green} />
+            <div class="save-contact">
+                if profile.website.is_some() {
+                    <TagList>
+                        <Tag active={*target == QrTarget::Contact} onclick={select(QrTarget::Contact)}>
+                            {"Contact card"}
+                        </Tag>
+                        <Tag active={*target == QrTarget::Website} onclick={select(QrTarget::Website)}>
+                            {"Website"}
+                        </Tag>
+                    </TagList>
+                }
+                <QrCode data={data} label={caption.clone()} />
+                <p class="save-contact-caption">{caption}</p>
+                <button class="vcard-btn" onclick={on_download}>
+                    <i class="fas fa-download"></i>
+                    {"Download vCard"}
+                </button>
+            </div>
+        </Card>
+    }
+}
@@ -0,0 +1,95 @@
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+/// Color of a section's icon.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Tone {
+    #[default]
+    Blue,
+    Green,
+    Purple,
+}
+
+impl Tone {
+    fn class(&self) -> &'static str {
+        match self {
+            Tone::Blue => "blue",
+            Tone::Green => "green",
+            Tone::Purple => "purple",
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SectionHeaderProps {
+    pub title: AttrValue,
+    /// Font Awesome classes of an icon shown before the title, which also makes the header larger.
+    #[prop_or_default]
+    pub icon: Option<AttrValue>,
+    #[prop_or_default]
+    pub tone: Tone,
+}
+
+/// Title of a section within a page, usually at the top of a [`super::Card`].
+#[styled_component(SectionHeader)]
+pub fn section_header(props: &SectionHeaderProps) -> Html {
+    let style = css!(
+        r#"
+        h2 {
+            color: var(--color-accent);
+            font-size: 1.25rem;
+            font-weight: 600;
+            margin: 0 0 var(--space-md);
+        }
+
+        &.with-icon {
+            display: flex;
+            align-items: center;
+            gap: var(--space-md);
+            margin-bottom: 28px;
+            padding-bottom: var(--space-md);
+            border-bottom: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+        }
+
+        &.with-icon h2 {
+            color: var(--color-heading);
+            font-size: 1.75rem;
+            margin: 0;
+        }
+
+        .section-icon {
+            width: 48px;
+            height: 48px;
+            border-radius: var(--radius-md);
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            font-size: 1.5rem;
+            color: white;
+        }
+
+        .section-icon.blue {
+            background: linear-gradient(135deg, var(--color-primary), var(--color-link));
+        }
+
+        .section-icon.green {
+            background: linear-gradient(135deg, var(--color-success), var(--color-success-text));
+        }
+
+        .section-icon.purple {
+            background: linear-gradient(135deg, var(--color-secondary), var(--color-accent));
+        }
+        "#
+    );
+
+    html! {
+        <div class={classes!(style, props.icon.is_some().then_some("with-icon"))}>
+            if let Some(icon) = &props.icon {
+                <div class={classes!("section-icon", props.tone.class())}>
+                    <i class={icon.clone()}></i>
+                </div>
+            }
+            <h2>{props.title.clone()}</h2>
+        </div>
+    }
+}
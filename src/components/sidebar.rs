@@ -1,13 +1,13 @@
-use crate::{data::Profile, Route};
-use stylist::{yew::styled_component, Style};
+use super::{ProfileAvatar, ThemeToggle};
+use crate::content::{use_posts, use_profile};
+use crate::export::{self, vcard};
+use crate::{config::Config, Route};
+use stylist::yew::styled_component;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-const SHOW_LEGAL: bool = false;
-
 #[derive(Properties, PartialEq)]
 pub struct SidebarProps {
-    pub profile: Profile,
     pub current_route: Route,
     pub is_mobile: bool,
     pub is_open: bool,
@@ -18,13 +18,41 @@ pub struct SidebarProps {
 pub fn sidebar(props: &SidebarProps) -> Html {
     // Get the current route directly from the router hook
     let current_route = use_route::<Route>().unwrap_or(Route::About);
+    let config = use_context::<Config>().expect("Config context is missing");
+    let profile = use_profile();
+    let posts = use_posts();
+
+    let on_save_contact = {
+        let profile = profile.clone();
+        Callback::from(move |_: MouseEvent| {
+            let card = vcard::render(&profile);
+            export::download(&vcard::file_name(&profile), vcard::MIME_TYPE, card.as_bytes());
+        })
+    };
 
-    let style = Style::new(css!(
+    let style = css!(
         r#"
+        .profile-headline {
+            margin-top: 16px;
+            font-size: 0.875rem;
+            line-height: 1.5;
+            color: var(--color-text-secondary);
+        }
+
+        .profile-location {
+            margin-top: 8px;
+            font-size: 0.875rem;
+            color: var(--color-text-muted);
+        }
+
+        .profile-location i {
+            margin-right: 6px;
+        }
+
         .sidebar-footer {
             padding: 24px;
             flex-shrink: 0;
-            border-top: 1px solid rgba(255, 255, 255, 0.08);
+            border-top: 1px solid rgba(var(--color-overlay-rgb), 0.08);
             margin-top: auto;
         }
 
@@ -33,7 +61,7 @@ pub fn sidebar(props: &SidebarProps) -> Html {
         }
 
         .social-links p {
-            color: #9ca3af;
+            color: var(--color-text-muted);
             font-size: 0.875rem;
             margin-bottom: 16px;
             font-weight: 500;
@@ -43,6 +71,7 @@ pub fn sidebar(props: &SidebarProps) -> Html {
         .social-icons {
             display: flex;
             justify-content: center;
+            flex-wrap: wrap;
             gap: 16px;
         }
 
@@ -52,21 +81,26 @@ pub fn sidebar(props: &SidebarProps) -> Html {
             justify-content: center;
             width: 44px;
             height: 44px;
-            background: rgba(255, 255, 255, 0.05);
-            border: 1px solid rgba(255, 255, 255, 0.1);
+            background: rgba(var(--color-overlay-rgb), 0.05);
+            border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
             border-radius: 12px;
-            color: #9ca3af;
+            color: var(--color-text-muted);
             text-decoration: none;
             transition: all 0.2s ease;
             backdrop-filter: blur(10px);
         }
 
+        button.social-icon {
+            font: inherit;
+            cursor: pointer;
+        }
+
         .social-icon:hover {
-            background: rgba(255, 255, 255, 0.1);
-            border-color: rgba(96, 165, 250, 0.5);
-            color: #60a5fa;
+            background: rgba(var(--color-overlay-rgb), 0.1);
+            border-color: rgba(var(--color-link-rgb), 0.5);
+            color: var(--color-link);
             transform: translateY(-2px);
-            box-shadow: 0 4px 12px rgba(96, 165, 250, 0.15);
+            box-shadow: 0 4px 12px rgba(var(--color-link-rgb), 0.15);
         }
 
         .social-icon i {
@@ -81,19 +115,55 @@ pub fn sidebar(props: &SidebarProps) -> Html {
 
         .social-icon.github:hover {
             border-color: rgba(107, 114, 128, 0.5);
-            color: #e5e7eb;
+            color: var(--color-text);
             box-shadow: 0 4px 12px rgba(107, 114, 128, 0.15);
         }
 
+        .social-icon.gitlab:hover {
+            border-color: rgba(252, 109, 38, 0.5);
+            color: #fc6d26;
+            box-shadow: 0 4px 12px rgba(252, 109, 38, 0.15);
+        }
+
+        .social-icon.mastodon:hover {
+            border-color: rgba(99, 100, 255, 0.5);
+            color: #8c8dff;
+            box-shadow: 0 4px 12px rgba(99, 100, 255, 0.15);
+        }
+
+        .social-icon.x:hover {
+            border-color: rgba(229, 231, 235, 0.5);
+            color: var(--color-heading);
+            box-shadow: 0 4px 12px rgba(229, 231, 235, 0.15);
+        }
+
+        .social-icon.stack-overflow:hover {
+            border-color: rgba(244, 128, 36, 0.5);
+            color: #f48024;
+            box-shadow: 0 4px 12px rgba(244, 128, 36, 0.15);
+        }
+
+        .social-icon.blog:hover {
+            border-color: rgba(var(--color-warning-rgb), 0.5);
+            color: var(--color-warning);
+            box-shadow: 0 4px 12px rgba(var(--color-warning-rgb), 0.15);
+        }
+
+        .social-icon.website:hover {
+            border-color: rgba(var(--color-success-rgb), 0.5);
+            color: var(--color-success-text);
+            box-shadow: 0 4px 12px rgba(var(--color-success-rgb), 0.15);
+        }
+
         .social-icon.email:hover {
-            border-color: rgba(139, 92, 246, 0.5);
-            color: #a78bfa;
-            box-shadow: 0 4px 12px rgba(139, 92, 246, 0.15);
+            border-color: rgba(var(--color-secondary-rgb), 0.5);
+            color: var(--color-accent);
+            box-shadow: 0 4px 12px rgba(var(--color-secondary-rgb), 0.15);
         }
 
         .legal-links {
             padding: 20px 24px;
-            border-top: 1px solid rgba(255, 255, 255, 0.08);
+            border-top: 1px solid rgba(var(--color-overlay-rgb), 0.08);
             display: flex;
             justify-content: center;
             gap: 20px;
@@ -102,21 +172,20 @@ pub fn sidebar(props: &SidebarProps) -> Html {
 
         .legal-link {
             font-size: 0.875rem;
-            color: #6b7280;
+            color: var(--color-text-subtle);
             text-decoration: none;
             transition: color 0.2s;
         }
 
         .legal-link:hover {
-            color: #9ca3af;
+            color: var(--color-text-muted);
         }
 
         .legal-separator {
-            color: #4b5563;
+            color: var(--color-text-subtle);
         }
         "#
-    ))
-    .expect("Failed to create style");
+    );
 
     let sidebar_class = if props.is_mobile {
         if props.is_open {
@@ -128,27 +197,26 @@ pub fn sidebar(props: &SidebarProps) -> Html {
         "sidebar sidebar-desktop"
     };
 
-    let initials = props
-        .profile
-        .name
-        .split_whitespace()
-        .filter_map(|word| word.chars().next())
-        .collect::<String>()
-        .to_uppercase();
-
     html! {
         <div class={style}>
-            <div class={sidebar_class}>
+            <div class={classes!(sidebar_class, "h-card")}>
                 <div class="sidebar-profile">
                     <div class="profile-info">
-                        <div class="profile-avatar">
-                            {initials}
-                        </div>
+                        <ProfileAvatar />
                         <div class="profile-details">
-                            <h2>{&props.profile.name}</h2>
-                            <p>{&props.profile.title}</p>
+                            <h2 class="p-name">{&profile.name}</h2>
+                            <p class="p-job-title">{&profile.title}</p>
                         </div>
                     </div>
+                    if let Some(headline) = profile.headline.as_ref() {
+                        <p class="profile-headline p-note">{headline}</p>
+                    }
+                    if let Some(location) = profile.location.as_ref() {
+                        <p class="profile-location">
+                            <i class="fas fa-map-marker-alt"></i>
+                            <span class="p-locality">{location}</span>
+                        </p>
+                    }
                 </div>
 
                 <nav class="sidebar-nav">
@@ -170,12 +238,22 @@ pub fn sidebar(props: &SidebarProps) -> Html {
 
                     <Link<Route>
                         to={Route::Projects}
-                        classes={if current_route == Route::Projects { "nav-item active" } else { "nav-item" }}
+                        classes={if matches!(current_route, Route::Projects | Route::ProjectDetail { .. }) { "nav-item active" } else { "nav-item" }}
                     >
                         <i class="fas fa-folder"></i>
                         {"Projects"}
                     </Link<Route>>
 
+                    if !posts.is_empty() {
+                        <Link<Route>
+                            to={Route::Blog}
+                            classes={if matches!(current_route, Route::Blog | Route::Post { .. } | Route::Tag { .. }) { "nav-item active" } else { "nav-item" }}
+                        >
+                            <i class="fas fa-pen-nib"></i>
+                            {"Blog"}
+                        </Link<Route>>
+                    }
+
                     <Link<Route>
                         to={Route::Contact}
                         classes={if current_route == Route::Contact { "nav-item active" } else { "nav-item" }}
@@ -185,49 +263,75 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                     </Link<Route>>
                 </nav>
 
-                if props.profile.show_get_in_touch() {
-                    <div class="sidebar-footer">
-                        <div class="social-links">
+                <div class="sidebar-footer">
+                    <div class="social-links">
+                        // The contact card is worth saving even without a way to get in touch
+                        if profile.show_get_in_touch() {
                             <p>{"Get in touch"}</p>
-                            <div class="social-icons">
-                                if let Some(linkedin) = props.profile.linkedin.as_ref() {
-                                    <a href={linkedin.clone()} target="_blank" rel="noopener noreferrer" class="social-icon linkedin">
-                                        <i class="fab fa-linkedin"></i>
-                                    </a>
-                                }
-                                if let Some(github) = props.profile.github.as_ref() {
-                                    <a href={github.clone()} target="_blank" rel="noopener noreferrer" class="social-icon github">
-                                        <i class="fab fa-github"></i>
-                                    </a>
-                                }
-                                if let Some(email) = props.profile.email.as_ref() {
-                                    <a href={format!("mailto:{email}")} target="_blank" rel="noopener noreferrer" class="social-icon email">
-                                        <i class="fas fa-envelope"></i>
-                                    </a>
-                                }
-                            </div>
+                        }
+                        <div class="social-icons">
+                            {for profile.social_links().into_iter().map(|link| html! {
+                                <a
+                                    href={link.url.clone()}
+                                    target="_blank"
+                                    rel="me noopener noreferrer"
+                                    class={classes!("social-icon", "u-url", link.class())}
+                                    title={link.label().to_string()}
+                                    aria-label={link.label().to_string()}
+                                >
+                                    <i class={link.icon()}></i>
+                                </a>
+                            })}
+                            if let Some(email) = profile.email.as_ref() {
+                                <a href={format!("mailto:{email}")} target="_blank" rel="noopener noreferrer" class="social-icon email u-email">
+                                    <i class="fas fa-envelope"></i>
+                                </a>
+                            }
+                            <button
+                                class="social-icon vcard"
+                                title="Save contact"
+                                aria-label="Save contact"
+                                onclick={on_save_contact}
+                            >
+                                <i class="fas fa-address-card"></i>
+                            </button>
                         </div>
                     </div>
-                }
-                
-                if SHOW_LEGAL {
-                    <div class="legal-links">
-                        <Link<Route>
-                            to={Route::Terms}
-                            classes="legal-link"
-                        >
-                            {"Terms"}
-                        </Link<Route>>
-                        <span class="legal-separator">{"·"}</span>
-                        <Link<Route>
-                            to={Route::Privacy}
-                            classes="legal-link"
-                        >
-                            {"Privacy"}
-                        </Link<Route>>
-                    </div>
+                </div>
+
+                <ThemeToggle />
+
+                if config.show_legal {
+                    {legal_links()}
                 }
             </div>
         </div>
     }
 }
+
+#[cfg(feature = "legal-pages")]
+fn legal_links() -> Html {
+    html! {
+        <div class="legal-links">
+            <Link<Route>
+                to={Route::Terms}
+                classes="legal-link"
+            >
+                {"Terms"}
+            </Link<Route>>
+            <span class="legal-separator">{"·"}</span>
+            <Link<Route>
+                to={Route::Privacy}
+                classes="legal-link"
+            >
+                {"Privacy"}
+            </Link<Route>>
+        </div>
+    }
+}
+
+// `show_legal` is always false without the feature, but the routes it links to no longer exist
+#[cfg(not(feature = "legal-pages"))]
+fn legal_links() -> Html {
+    Html::default()
+}
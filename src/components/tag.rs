@@ -0,0 +1,91 @@
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TagProps {
+    #[prop_or_default]
+    pub children: Html,
+    /// Highlight the tag, e.g. as a selected filter.
+    #[prop_or_default]
+    pub active: bool,
+    /// Makes the tag a toggle button.
+    #[prop_or_default]
+    pub onclick: Option<Callback<MouseEvent>>,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A rounded label for a technology, skill or keyword.
+#[styled_component(Tag)]
+pub fn tag(props: &TagProps) -> Html {
+    let style = css!(
+        r#"
+        background: rgba(var(--color-primary-rgb), 0.1);
+        border: 1px solid rgba(var(--color-primary-rgb), 0.3);
+        color: var(--color-link-strong);
+        padding: 6px 14px;
+        border-radius: var(--radius-pill);
+        font-size: 0.875rem;
+        font-family: inherit;
+        transition: all 0.2s;
+
+        &:hover {
+            background: rgba(var(--color-primary-rgb), 0.2);
+            border-color: rgba(var(--color-primary-rgb), 0.5);
+        }
+
+        &.toggle {
+            cursor: pointer;
+        }
+
+        &.active {
+            background: linear-gradient(45deg, var(--color-primary), var(--color-secondary));
+            border-color: transparent;
+            color: white;
+        }
+        "#
+    );
+
+    let classes = classes!(
+        style,
+        props.onclick.is_some().then_some("toggle"),
+        props.active.then_some("active"),
+        props.class.clone(),
+    );
+    match &props.onclick {
+        Some(onclick) => html! {
+            <button class={classes} aria-pressed={props.active.to_string()} onclick={onclick.clone()}>
+                {props.children.clone()}
+            </button>
+        },
+        None => html! {
+            <span class={classes}>{props.children.clone()}</span>
+        },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TagListProps {
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Lays tags out in wrapping rows.
+#[styled_component(TagList)]
+pub fn tag_list(props: &TagListProps) -> Html {
+    let style = css!(
+        r#"
+        display: flex;
+        flex-wrap: wrap;
+        gap: 10px;
+        "#
+    );
+
+    html! {
+        <div class={classes!(style, props.class.clone())}>
+            {props.children.clone()}
+        </div>
+    }
+}
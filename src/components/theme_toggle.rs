@@ -0,0 +1,70 @@
+use crate::theme::{use_theme, ThemeMode};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[styled_component(ThemeToggle)]
+pub fn theme_toggle() -> Html {
+    let theme = use_theme();
+
+    let style = css!(
+        r#"
+        display: flex;
+        justify-content: center;
+        gap: 4px;
+        margin: 0 24px 20px;
+        padding: 4px;
+        background: rgba(var(--color-overlay-rgb), 0.05);
+        border: 1px solid rgba(var(--color-overlay-rgb), 0.1);
+        border-radius: 10px;
+
+        button {
+            flex: 1;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            gap: 6px;
+            padding: 6px 8px;
+            background: none;
+            border: none;
+            border-radius: 6px;
+            color: var(--color-text-muted);
+            font-family: inherit;
+            font-size: 0.75rem;
+            cursor: pointer;
+            transition: all 0.2s;
+        }
+
+        button:hover {
+            color: var(--color-heading);
+        }
+
+        button.active {
+            background: rgba(var(--color-primary-rgb), 0.15);
+            color: var(--color-link);
+        }
+        "#
+    );
+
+    html! {
+        <div class={style} role="group" aria-label="Theme">
+            {for ThemeMode::ALL.into_iter().map(|mode| {
+                let active = *theme == mode;
+                let onclick = {
+                    let theme = theme.clone();
+                    Callback::from(move |_: MouseEvent| theme.set(mode))
+                };
+                html! {
+                    <button
+                        class={classes!(active.then_some("active"))}
+                        aria-pressed={active.to_string()}
+                        title={format!("{} theme", mode.label())}
+                        {onclick}
+                    >
+                        <i class={mode.icon()}></i>
+                        {mode.label()}
+                    </button>
+                }
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,193 @@
+use serde::Deserialize;
+use std::fmt;
+
+/// Where deployments can drop a `config.json` to override the values baked in at build time.
+pub const RUNTIME_CONFIG_PATH: &str = "/config.json";
+
+/// Site settings, read from the build environment (`API_SERVER`, `ENABLE_CONTACT_FORM`,
+/// `SHOW_LEGAL`, `CONTENT_URL`) and optionally overridden at startup by [`RUNTIME_CONFIG_PATH`].
+///
+/// The toggles only take effect when the `contact-form` and `legal-pages` features are enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub api_url: Option<String>,
+    pub contact_form: bool,
+    pub show_legal: bool,
+    /// Where to load the content from at startup instead of using the copy compiled in, either a
+    /// full url or a path on this site such as `/data`.
+    pub content_url: Option<String>,
+}
+
+/// Shape of the runtime `config.json`; every field is optional.
+#[derive(Default, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigOverrides {
+    pub api_url: Option<String>,
+    pub contact_form: Option<bool>,
+    pub show_legal: Option<bool>,
+    pub content_url: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    InvalidApiUrl(String),
+    InvalidContentUrl(String),
+    MissingApiUrl,
+    Runtime(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidApiUrl(url) => {
+                write!(f, "The API server `{url}` is not a valid http(s) url.")
+            }
+            ConfigError::InvalidContentUrl(url) => write!(
+                f,
+                "The content url `{url}` is neither an http(s) url nor a path starting with `/`."
+            ),
+            ConfigError::MissingApiUrl => f.write_str(
+                "The contact form is enabled but no API server is configured. Set `API_SERVER` \
+                 when building or `api_url` in config.json.",
+            ),
+            ConfigError::Runtime(reason) => write!(f, "{RUNTIME_CONFIG_PATH} is invalid: {reason}"),
+        }
+    }
+}
+
+fn flag(value: Option<&str>) -> bool {
+    matches!(value, Some("1" | "true"))
+}
+
+fn normalize_url(url: &str) -> Option<String> {
+    let url = url.trim().trim_end_matches('/');
+    (!url.is_empty()).then(|| url.to_string())
+}
+
+fn is_http_url(url: &str) -> bool {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.split('/').next())
+        .is_some_and(|host| !host.is_empty())
+}
+
+fn is_content_url(url: &str) -> bool {
+    is_http_url(url) || url.starts_with('/')
+}
+
+impl Config {
+    pub fn from_build() -> Self {
+        Self {
+            api_url: option_env!("API_SERVER").and_then(normalize_url),
+            contact_form: cfg!(feature = "contact-form") && flag(option_env!("ENABLE_CONTACT_FORM")),
+            show_legal: cfg!(feature = "legal-pages") && flag(option_env!("SHOW_LEGAL")),
+            content_url: option_env!("CONTENT_URL").and_then(normalize_url),
+        }
+    }
+
+    /// The configuration for the first render and the prerendered pages, which can't wait for the
+    /// runtime overrides. Those may still supply a missing or broken url, so until they are in,
+    /// invalid urls are left out and a contact form without an API falls back to the contact cards.
+    pub fn initial() -> Self {
+        let config = Self::from_build();
+        let api_url = config.api_url.filter(|url| is_http_url(url));
+        Self {
+            contact_form: config.contact_form && api_url.is_some(),
+            api_url,
+            content_url: config.content_url.filter(|url| is_content_url(url)),
+            ..config
+        }
+    }
+
+    pub fn merge(self, overrides: ConfigOverrides) -> Self {
+        Self {
+            api_url: overrides
+                .api_url
+                .as_deref()
+                .map_or(self.api_url, normalize_url),
+            contact_form: cfg!(feature = "contact-form")
+                && overrides.contact_form.unwrap_or(self.contact_form),
+            show_legal: cfg!(feature = "legal-pages")
+                && overrides.show_legal.unwrap_or(self.show_legal),
+            content_url: overrides
+                .content_url
+                .as_deref()
+                .map_or(self.content_url, normalize_url),
+        }
+    }
+
+    pub fn validate(self) -> Result<Self, ConfigError> {
+        if let Some(url) = self.api_url.as_ref().filter(|url| !is_http_url(url)) {
+            return Err(ConfigError::InvalidApiUrl(url.clone()));
+        }
+
+        if let Some(url) = &self.content_url {
+            if !is_content_url(url) {
+                return Err(ConfigError::InvalidContentUrl(url.clone()));
+            }
+        }
+
+        if self.contact_form && self.api_url.is_none() {
+            return Err(ConfigError::MissingApiUrl);
+        }
+
+        Ok(self)
+    }
+
+    /// Endpoint the contact form posts to, if the form is enabled.
+    pub fn contact_url(&self) -> Option<String> {
+        self.api_url
+            .as_ref()
+            .filter(|_| self.contact_form)
+            .map(|url| format!("{url}/v1/contact"))
+    }
+
+    /// Turns the contact form off when its API does not pass the health check, so visitors get
+    /// the contact cards instead of a form that can only fail.
+    pub async fn check_api(self) -> Self {
+        #[cfg(feature = "contact-form")]
+        if let Some(url) = self.api_url.as_ref().filter(|_| self.contact_form) {
+            let healthy = reqwest::Client::new()
+                .get(format!("{url}/v1/health"))
+                .timeout(std::time::Duration::from_secs(5))
+                .send()
+                .await
+                .is_ok_and(|response| response.status().is_success());
+            if !healthy {
+                return Self {
+                    contact_form: false,
+                    ..self
+                };
+            }
+        }
+
+        self
+    }
+}
+
+/// Whether a response holds json rather than a page. Static hosts commonly answer unknown paths
+/// with the app shell instead of a 404, so a successful status alone doesn't mean the file exists.
+pub(crate) fn is_json(response: &gloo::net::http::Response) -> bool {
+    response
+        .headers()
+        .get("content-type")
+        .is_some_and(|content_type| content_type.contains("json"))
+}
+
+/// Fetches the runtime overrides, treating a missing file as no overrides.
+pub async fn fetch_overrides() -> Result<Option<ConfigOverrides>, ConfigError> {
+    let response = match gloo::net::http::Request::get(RUNTIME_CONFIG_PATH).send().await {
+        Ok(response) => response,
+        Err(_) => return Ok(None),
+    };
+
+    if !response.ok() || !is_json(&response) {
+        return Ok(None);
+    }
+
+    response
+        .json::<ConfigOverrides>()
+        .await
+        .map(Some)
+        .map_err(|err| ConfigError::Runtime(err.to_string()))
+}
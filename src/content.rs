@@ -0,0 +1,255 @@
+use crate::config;
+use crate::data::{self, About, Post, Profile, Project, Resume};
+#[cfg(feature = "legal-pages")]
+use crate::data::{Privacy, Terms};
+use gloo::storage::{LocalStorage, Storage};
+use serde::de::DeserializeOwned;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Files a remote source provides, each shaped like the datafile of the same name.
+const REMOTE_FILES: &[&str] = &[
+    "profile",
+    "resume",
+    "projects",
+    "about",
+    #[cfg(feature = "legal-pages")]
+    "terms",
+    #[cfg(feature = "legal-pages")]
+    "privacy",
+];
+
+/// Where the content on screen came from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContentStatus {
+    /// The datafiles compiled into the site, with no remote source configured.
+    Embedded,
+    /// The remote content is on its way, meanwhile the last cached or the embedded copy is shown.
+    Loading,
+    Remote,
+    /// Some of the remote content could not be loaded, so the cached or embedded copy of it is
+    /// shown instead.
+    Failed(String),
+}
+
+/// Everything the views display, provided once at the root of the app so each view reads the
+/// same copy and the source can change without touching them.
+#[derive(Clone, Debug)]
+pub struct Content {
+    pub profile: Rc<Profile>,
+    pub resume: Rc<Resume>,
+    /// Newest first.
+    pub projects: Rc<Vec<Project>>,
+    pub about: Rc<About>,
+    #[cfg(feature = "legal-pages")]
+    pub terms: Rc<Terms>,
+    #[cfg(feature = "legal-pages")]
+    pub privacy: Rc<Privacy>,
+    /// Newest first. Always the embedded posts, since they are rendered when the site is built.
+    pub posts: Rc<Vec<Post>>,
+    pub status: ContentStatus,
+}
+
+impl Content {
+    /// The datafiles compiled into the site.
+    pub fn embedded() -> Self {
+        Self {
+            profile: Rc::new(data::get_profile().clone()),
+            resume: Rc::new(data::get_resume().clone()),
+            projects: Rc::new(data::get_projects().to_vec()),
+            about: Rc::new(data::get_about().clone()),
+            #[cfg(feature = "legal-pages")]
+            terms: Rc::new(data::get_terms().clone()),
+            #[cfg(feature = "legal-pages")]
+            privacy: Rc::new(data::get_privacy().clone()),
+            posts: Rc::new(data::get_posts().to_vec()),
+            status: ContentStatus::Embedded,
+        }
+    }
+
+    /// Replaces each part that has json in `files`, keeping the current copy of any part whose
+    /// json is missing or does not parse. Returns the files that did not parse and why.
+    fn with_files(
+        &self,
+        files: &[(&'static str, Option<String>)],
+    ) -> (Self, Vec<(&'static str, String)>) {
+        fn replace<T: DeserializeOwned>(
+            part: &mut Rc<T>,
+            name: &'static str,
+            files: &[(&'static str, Option<String>)],
+            invalid: &mut Vec<(&'static str, String)>,
+        ) {
+            let Some(json) = files
+                .iter()
+                .find(|(file, _)| *file == name)
+                .and_then(|(_, json)| json.as_deref())
+            else {
+                return;
+            };
+            match serde_json::from_str(json) {
+                Ok(value) => *part = Rc::new(value),
+                Err(err) => invalid.push((name, err.to_string())),
+            }
+        }
+
+        let mut content = self.clone();
+        let mut invalid = Vec::new();
+        replace(&mut content.profile, "profile", files, &mut invalid);
+        replace(&mut content.resume, "resume", files, &mut invalid);
+        replace(&mut content.projects, "projects", files, &mut invalid);
+        replace(&mut content.about, "about", files, &mut invalid);
+        #[cfg(feature = "legal-pages")]
+        replace(&mut content.terms, "terms", files, &mut invalid);
+        #[cfg(feature = "legal-pages")]
+        replace(&mut content.privacy, "privacy", files, &mut invalid);
+
+        if !Rc::ptr_eq(&content.projects, &self.projects) {
+            let mut projects = (*content.projects).clone();
+            projects.sort_by_key(|project| std::cmp::Reverse(project.dates.latest()));
+            content.projects = Rc::new(projects);
+        }
+        (content, invalid)
+    }
+
+    /// The embedded content overlaid with what was last loaded from `base_url`.
+    pub fn cached(base_url: &str) -> Self {
+        let files = REMOTE_FILES
+            .iter()
+            .map(|name| (*name, LocalStorage::get(cache_key(base_url, name)).ok()))
+            .collect::<Vec<_>>();
+        let (content, _) = Self::embedded().with_files(&files);
+        Self {
+            status: ContentStatus::Loading,
+            ..content
+        }
+    }
+
+    /// Loads every file from `base_url` on top of `self`, caching the ones that parse. Parts that
+    /// fail keep their current copy and are reported in [`ContentStatus::Failed`].
+    pub async fn fetch(&self, base_url: &str) -> Self {
+        let responses = futures::future::join_all(
+            REMOTE_FILES
+                .iter()
+                .map(|name| fetch_file(format!("{base_url}/{name}.json"))),
+        )
+        .await;
+
+        let mut errors = Vec::new();
+        let files = REMOTE_FILES
+            .iter()
+            .zip(responses)
+            .map(|(name, response)| match response {
+                Ok(json) => (*name, Some(json)),
+                Err(err) => {
+                    errors.push((*name, err));
+                    (*name, None)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let (content, invalid) = self.with_files(&files);
+        for (name, json) in &files {
+            let valid = !invalid.iter().any(|(file, _)| file == name);
+            if let Some(json) = json.as_ref().filter(|_| valid) {
+                // Running out of storage only costs the cache
+                let _ = LocalStorage::set(cache_key(base_url, name), json);
+            }
+        }
+
+        errors.extend(invalid);
+        let status = if errors.is_empty() {
+            ContentStatus::Remote
+        } else {
+            let errors = errors
+                .iter()
+                .map(|(name, err)| format!("{name}.json: {err}"))
+                .collect::<Vec<_>>();
+            ContentStatus::Failed(errors.join("; "))
+        };
+        Self { status, ..content }
+    }
+
+    pub fn project(&self, slug: &str) -> Option<&Project> {
+        self.projects.iter().find(|project| project.slug() == slug)
+    }
+
+    pub fn post(&self, slug: &str) -> Option<&Post> {
+        self.posts.iter().find(|post| post.slug == slug)
+    }
+}
+
+// Parts are only ever replaced whole, so comparing pointers is enough and keeps re-renders cheap
+impl PartialEq for Content {
+    fn eq(&self, other: &Self) -> bool {
+        let same = Rc::ptr_eq(&self.profile, &other.profile)
+            && Rc::ptr_eq(&self.resume, &other.resume)
+            && Rc::ptr_eq(&self.projects, &other.projects)
+            && Rc::ptr_eq(&self.about, &other.about)
+            && Rc::ptr_eq(&self.posts, &other.posts);
+        #[cfg(feature = "legal-pages")]
+        let same = same
+            && Rc::ptr_eq(&self.terms, &other.terms)
+            && Rc::ptr_eq(&self.privacy, &other.privacy);
+        same && self.status == other.status
+    }
+}
+
+fn cache_key(base_url: &str, name: &str) -> String {
+    format!("content:{base_url}/{name}.json")
+}
+
+async fn fetch_file(url: String) -> Result<String, String> {
+    let response = gloo::net::http::Request::get(&url)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    if !response.ok() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    if !config::is_json(&response) {
+        return Err("not a json response".to_string());
+    }
+    response.text().await.map_err(|err| err.to_string())
+}
+
+#[hook]
+pub fn use_content() -> Content {
+    use_context::<Content>().expect("Content context is missing")
+}
+
+#[hook]
+pub fn use_profile() -> Rc<Profile> {
+    use_content().profile
+}
+
+#[hook]
+pub fn use_resume() -> Rc<Resume> {
+    use_content().resume
+}
+
+#[hook]
+pub fn use_projects() -> Rc<Vec<Project>> {
+    use_content().projects
+}
+
+#[hook]
+pub fn use_about() -> Rc<About> {
+    use_content().about
+}
+
+#[hook]
+pub fn use_posts() -> Rc<Vec<Post>> {
+    use_content().posts
+}
+
+#[cfg(feature = "legal-pages")]
+#[hook]
+pub fn use_terms() -> Rc<Terms> {
+    use_content().terms
+}
+
+#[cfg(feature = "legal-pages")]
+#[hook]
+pub fn use_privacy() -> Rc<Privacy> {
+    use_content().privacy
+}
@@ -0,0 +1,613 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A year with an optional month, written as `2022` or `2022-03` in the datafiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    pub year: i32,
+    /// 1 based; `None` when only the year is known.
+    pub month: Option<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateError {
+    value: String,
+    /// Examples of what was expected instead.
+    expected: &'static str,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a date like {}", self.value, self.expected)
+    }
+}
+
+impl std::error::Error for DateError {}
+
+impl YearMonth {
+    pub fn new(year: i32, month: Option<u8>) -> Self {
+        Self { year, month }
+    }
+
+    /// The current month, taken from the browser's clock or the system clock when prerendering.
+    pub fn now() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let date = js_sys::Date::new_0();
+            Self::new(date.get_full_year() as i32, Some(date.get_month() as u8 + 1))
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let days = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() / 86_400) as i64;
+            // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+            let z = days + 719_468;
+            let era = z.div_euclid(146_097);
+            let doe = z - era * 146_097;
+            let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let mp = (5 * doy + 2) / 153;
+            let month = if mp < 10 { mp + 3 } else { mp - 9 };
+            let year = yoe + era * 400 + i64::from(month <= 2);
+            Self::new(year as i32, Some(month as u8))
+        }
+    }
+
+    /// Months since year zero.
+    fn index(&self) -> i32 {
+        self.year * 12 + i32::from(self.month.unwrap_or(1)) - 1
+    }
+
+    /// The first day of the month, or of the year when there is no month.
+    pub fn first_day(&self) -> Date {
+        Date::new(self.year, self.month.unwrap_or(1), 1)
+    }
+
+    /// English label such as `Mar 2022`, for output that has no locale like the PDF.
+    pub fn label(&self) -> String {
+        match self.month {
+            Some(month) => format!("{} {}", MONTHS[usize::from(month - 1)], self.year),
+            None => self.year.to_string(),
+        }
+    }
+
+    /// Formats the date for the visitor's locale, e.g. `Mar 2022` or `März 2022`.
+    pub fn localized(&self) -> String {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(month) = self.month {
+            use wasm_bindgen::JsValue;
+
+            let options = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(&options, &"month".into(), &"short".into());
+            let _ = js_sys::Reflect::set(&options, &"year".into(), &"numeric".into());
+            let format = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &options);
+            let date = js_sys::Date::new_with_year_month_day(self.year as u32, i32::from(month) - 1, 1);
+            if let Some(formatted) = format
+                .format()
+                .call1(&JsValue::UNDEFINED, &date)
+                .ok()
+                .and_then(|formatted| formatted.as_string())
+            {
+                return formatted;
+            }
+        }
+
+        self.label()
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.month {
+            Some(month) => write!(f, "{}-{month:02}", self.year),
+            None => write!(f, "{}", self.year),
+        }
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = DateError;
+
+    /// Accepts `2022`, `2022-03`, `03/2022`, `Mar 2022` and `March 2022`. A trailing day, as in
+    /// the ISO `2022-03-15`, is dropped.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || DateError {
+            value: value.to_string(),
+            expected: "`2022`, `2022-03` or `Mar 2022`",
+        };
+        let value = value.trim();
+        let year = |year: &str| {
+            year.trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|year| (1000..=9999).contains(year))
+        };
+        let month = |month: &str| {
+            let month = month.trim();
+            month
+                .parse::<u8>()
+                .ok()
+                .or_else(|| {
+                    let prefix = month.get(..3)?;
+                    MONTHS
+                        .iter()
+                        .position(|name| name.eq_ignore_ascii_case(prefix))
+                        .map(|index| index as u8 + 1)
+                })
+                .filter(|month| (1..=12).contains(month))
+        };
+
+        if let Some(year) = year(value) {
+            return Ok(Self::new(year, None));
+        }
+        let (year, month) = if let Some((year_part, month_part)) = value.split_once('-') {
+            let (month_part, day) = month_part.split_once('-').unwrap_or((month_part, "1"));
+            let day = day
+                .trim()
+                .parse::<u8>()
+                .is_ok_and(|day| (1..=31).contains(&day));
+            (year(year_part), month(month_part).filter(|_| day))
+        } else if let Some((month_part, year_part)) = value.split_once('/') {
+            (year(year_part), month(month_part))
+        } else if let Some((month_part, year_part)) = value.rsplit_once(' ') {
+            (year(year_part), month(month_part.trim_end_matches(',')))
+        } else {
+            (None, None)
+        };
+        match (year, month) {
+            (Some(year), Some(month)) => Ok(Self::new(year, Some(month))),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl Serialize for YearMonth {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for YearMonth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Year(i32),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Year(year) => Ok(Self::new(year, None)),
+            Raw::Text(text) => text.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// A calendar day, written `2024-03-15` in the datafiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    /// 1 based.
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Reads a date written out like `July 1, 2025`, as the legal pages state when they take
+    /// effect, falling back to `2025-07-01`.
+    pub fn parse_written(value: &str) -> Option<Self> {
+        if let Ok(date) = value.parse() {
+            return Some(date);
+        }
+        let (month_day, year) = value.trim().rsplit_once(' ')?;
+        let (month, day) = month_day.trim_end_matches(',').rsplit_once(' ')?;
+        let month = format!("{month} {year}").parse::<YearMonth>().ok()?;
+        format!("{}-{}-{}", month.year, month.month?, day.trim())
+            .parse()
+            .ok()
+    }
+
+    /// Day of the week, from 0 for Sunday.
+    pub fn weekday(&self) -> u8 {
+        // Sakamoto's method
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = self.year - i32::from(self.month < 3);
+        let day = year + year / 4 - year / 100 + year / 400
+            + OFFSETS[usize::from(self.month - 1)]
+            + i32::from(self.day);
+        day.rem_euclid(7) as u8
+    }
+
+    /// English label such as `15 Mar 2024`.
+    pub fn label(&self) -> String {
+        format!("{} {} {}", self.day, MONTHS[usize::from(self.month - 1)], self.year)
+    }
+
+    /// Formats the date for the visitor's locale, e.g. `Mar 15, 2024` or `15. März 2024`.
+    pub fn localized(&self) -> String {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::JsValue;
+
+            let options = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(&options, &"day".into(), &"numeric".into());
+            let _ = js_sys::Reflect::set(&options, &"month".into(), &"short".into());
+            let _ = js_sys::Reflect::set(&options, &"year".into(), &"numeric".into());
+            let format = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &options);
+            let date = js_sys::Date::new_with_year_month_day(
+                self.year as u32,
+                i32::from(self.month) - 1,
+                i32::from(self.day),
+            );
+            if let Some(formatted) = format
+                .format()
+                .call1(&JsValue::UNDEFINED, &date)
+                .ok()
+                .and_then(|formatted| formatted.as_string())
+            {
+                return formatted;
+            }
+        }
+
+        self.label()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || DateError {
+            value: value.to_string(),
+            expected: "`2024-03-15`",
+        };
+        let mut parts = value.trim().splitn(3, '-');
+        let mut part = || parts.next().ok_or_else(error);
+        let year = part()?.parse::<i32>().map_err(|_| error())?;
+        let month = part()?.parse::<u8>().map_err(|_| error())?;
+        let day = part()?.parse::<u8>().map_err(|_| error())?;
+        let valid = (1000..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && (1..=Self::days_in_month(year, month)).contains(&day);
+        if !valid {
+            return Err(error());
+        }
+        Ok(Self::new(year, month, day))
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// End of a date range, which may still be ongoing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateEnd {
+    Present,
+    On(YearMonth),
+}
+
+impl FromStr for DateEnd {
+    type Err = DateError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "present" | "current" | "now" | "ongoing" => Ok(DateEnd::Present),
+            _ => value.parse().map(DateEnd::On),
+        }
+    }
+}
+
+impl Serialize for DateEnd {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DateEnd::Present => serializer.serialize_str("present"),
+            DateEnd::On(date) => date.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DateEnd {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Year(i32),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Year(year) => Ok(DateEnd::On(YearMonth::new(year, None))),
+            Raw::Text(text) => text.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// When something happened: a range such as a role, or a single point such as a release when
+/// there is no `end`.
+///
+/// Flattened into the datafile entries as `start` and `end`. The free-form `duration`, `year` and
+/// `date` strings used before, like `"2022 - 2025"`, are still accepted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "DateFields", into = "DateFields")]
+pub struct Dates {
+    pub start: Option<YearMonth>,
+    pub end: Option<DateEnd>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct DateFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<YearMonth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<DateEnd>,
+    #[serde(alias = "year", alias = "date", skip_serializing)]
+    duration: Option<String>,
+}
+
+impl TryFrom<DateFields> for Dates {
+    type Error = DateError;
+
+    fn try_from(fields: DateFields) -> Result<Self, Self::Error> {
+        match fields.duration {
+            Some(duration) if fields.start.is_none() && fields.end.is_none() => duration.parse(),
+            _ => Ok(Self {
+                start: fields.start,
+                end: fields.end,
+            }),
+        }
+    }
+}
+
+impl From<Dates> for DateFields {
+    fn from(dates: Dates) -> Self {
+        Self {
+            start: dates.start,
+            end: dates.end,
+            duration: None,
+        }
+    }
+}
+
+impl FromStr for Dates {
+    type Err = DateError;
+
+    /// Parses ranges like `2022 - 2025`, `Mar 2021 – Present` or a single `2023`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let range = [" - ", " – ", " — ", "–", "—", " to "]
+            .iter()
+            .find_map(|separator| value.split_once(separator));
+        match range {
+            Some((start, end)) => Ok(Self {
+                start: Some(start.parse()?),
+                end: Some(end.parse()?),
+            }),
+            None => Ok(Self {
+                start: Some(value.parse()?),
+                end: None,
+            }),
+        }
+    }
+}
+
+impl Dates {
+    /// The latest point covered, used to order entries newest first.
+    pub fn latest(&self) -> Option<YearMonth> {
+        match self.end {
+            Some(DateEnd::Present) => Some(YearMonth::new(i32::MAX, None)),
+            Some(DateEnd::On(end)) => Some(end),
+            None => self.start,
+        }
+    }
+
+    fn format_with(&self, format: impl Fn(&YearMonth) -> String) -> Option<String> {
+        let start = format(&self.start?);
+        Some(match self.end {
+            None => start,
+            Some(DateEnd::On(end)) if Some(end) == self.start => start,
+            Some(DateEnd::Present) => format!("{start} – Present"),
+            Some(DateEnd::On(end)) => format!("{start} – {}", format(&end)),
+        })
+    }
+
+    /// English label such as `Mar 2021 – Present`.
+    pub fn label(&self) -> Option<String> {
+        self.format_with(YearMonth::label)
+    }
+
+    /// Formats the dates for the visitor's locale.
+    pub fn localized(&self) -> Option<String> {
+        self.format_with(YearMonth::localized)
+    }
+
+    /// Time between the start and end, or until `today` when still ongoing.
+    pub fn tenure(&self, today: YearMonth) -> Option<Tenure> {
+        let start = self.start?;
+        let end = match self.end? {
+            DateEnd::Present => today,
+            DateEnd::On(end) => end,
+        };
+
+        // Only count months when both ends have them, otherwise "2020 - 2021" would read as two years
+        if start.month.is_some() && end.month.is_some() {
+            let months = end.index() - start.index() + 1;
+            Some(Tenure::Months(months.max(1) as u32))
+        } else {
+            Some(Tenure::Years((end.year - start.year).max(0) as u32))
+        }
+    }
+}
+
+/// Length of a role, at the precision its dates allow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tenure {
+    Months(u32),
+    Years(u32),
+}
+
+impl fmt::Display for Tenure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: u32, unit: &str| match count {
+            1 => format!("1 {unit}"),
+            count => format!("{count} {unit}s"),
+        };
+        match *self {
+            Tenure::Years(0) => f.write_str("less than a year"),
+            Tenure::Years(years) => f.write_str(&plural(years, "yr")),
+            Tenure::Months(months) => match (months / 12, months % 12) {
+                (0, months) => f.write_str(&plural(months, "mo")),
+                (years, 0) => f.write_str(&plural(years, "yr")),
+                (years, months) => write!(f, "{} {}", plural(years, "yr"), plural(months, "mo")),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateEnd, Dates, Tenure, YearMonth};
+
+    fn month(year: i32, month: u8) -> YearMonth {
+        YearMonth::new(year, Some(month))
+    }
+
+    #[test]
+    fn parses_year_ranges() {
+        let dates = "2022 - 2025".parse::<Dates>().unwrap();
+        assert_eq!(dates.start, Some(YearMonth::new(2022, None)));
+        assert_eq!(dates.end, Some(DateEnd::On(YearMonth::new(2025, None))));
+    }
+
+    #[test]
+    fn parses_ongoing_ranges_with_an_en_dash() {
+        let dates = "Mar 2021 – Present".parse::<Dates>().unwrap();
+        assert_eq!(dates.start, Some(month(2021, 3)));
+        assert_eq!(dates.end, Some(DateEnd::Present));
+        assert_eq!(dates.label().as_deref(), Some("Mar 2021 – Present"));
+
+        assert_eq!("Mar 2021–Present".parse::<Dates>().unwrap(), dates);
+        assert_eq!("March 2021 to present".parse::<Dates>().unwrap(), dates);
+    }
+
+    #[test]
+    fn reads_the_legacy_duration_field() {
+        let dates = serde_json::from_str::<Dates>(r#"{ "duration": "2019 - Jun 2020" }"#).unwrap();
+        assert_eq!(dates.start, Some(YearMonth::new(2019, None)));
+        assert_eq!(dates.end, Some(DateEnd::On(month(2020, 6))));
+
+        let date = serde_json::from_str::<Dates>(r#"{ "date": "2023" }"#).unwrap();
+        assert_eq!(date.start, Some(YearMonth::new(2023, None)));
+        assert_eq!(date.end, None);
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(
+            "2022-03-15".parse::<Date>().unwrap(),
+            Date::new(2022, 3, 15)
+        );
+        assert_eq!("2022-03-15".parse::<YearMonth>().unwrap(), month(2022, 3));
+        assert_eq!(
+            Date::parse_written("July 1, 2025"),
+            Some(Date::new(2025, 7, 1))
+        );
+        assert_eq!(
+            Date::parse_written("2025-07-01"),
+            Some(Date::new(2025, 7, 1))
+        );
+        assert_eq!(Date::parse_written("February 30, 2025"), None);
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-02-29".parse::<Date>().is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_month_names() {
+        for value in ["Smarch 2022", "Ju 2022", "2022-Foo", "Present 2022"] {
+            assert!(value.parse::<YearMonth>().is_err(), "{value} parsed");
+        }
+        assert!("Smarch 2021 - 2022".parse::<Dates>().is_err());
+        assert_eq!(Date::parse_written("Smarch 1, 2025"), None);
+    }
+
+    #[test]
+    fn rejects_months_out_of_range() {
+        for value in [
+            "2022-13",
+            "2022-00",
+            "13/2022",
+            "0/2022",
+            "2022-03-32",
+            "999-03",
+        ] {
+            assert!(value.parse::<YearMonth>().is_err(), "{value} parsed");
+        }
+        assert!("2022-13-01".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn counts_tenure_across_a_year_boundary() {
+        let dates = "Nov 2021 - Feb 2022".parse::<Dates>().unwrap();
+        let tenure = dates.tenure(month(2030, 1)).unwrap();
+        assert_eq!(tenure, Tenure::Months(4));
+        assert_eq!(tenure.to_string(), "4 mos");
+
+        let dates = "Dec 2020 - Jan 2022".parse::<Dates>().unwrap();
+        assert_eq!(
+            dates.tenure(month(2030, 1)).unwrap().to_string(),
+            "1 yr 2 mos"
+        );
+
+        // Without months only whole years count, so a boundary crossed within a year is none
+        let dates = "2021 - 2022".parse::<Dates>().unwrap();
+        assert_eq!(dates.tenure(month(2030, 1)), Some(Tenure::Years(1)));
+        let dates = "2022 - 2022".parse::<Dates>().unwrap();
+        assert_eq!(
+            dates.tenure(month(2030, 1)).unwrap().to_string(),
+            "less than a year"
+        );
+
+        let ongoing = "Oct 2023 - Present".parse::<Dates>().unwrap();
+        assert_eq!(ongoing.tenure(month(2024, 3)), Some(Tenure::Months(6)));
+    }
+}
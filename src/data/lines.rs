@@ -0,0 +1,125 @@
+//! Where each value sits in a json datafile, so `build.rs` can report problems at their line.
+//! Kept free of dependencies so it can be included there as well as tested here.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Line of every value in a json document, keyed by paths like `experience[2].start`.
+pub fn value_lines(source: &str) -> HashMap<String, usize> {
+    enum Frame {
+        Object(Option<String>),
+        Array(usize),
+    }
+
+    fn path(stack: &[Frame]) -> String {
+        let mut path = String::new();
+        for frame in stack {
+            match frame {
+                Frame::Object(Some(key)) if path.is_empty() => path.push_str(key),
+                Frame::Object(Some(key)) => write!(path, ".{key}").unwrap(),
+                Frame::Object(None) => {}
+                Frame::Array(index) => write!(path, "[{index}]").unwrap(),
+            }
+        }
+        path
+    }
+
+    let mut lines = HashMap::new();
+    let mut stack = Vec::new();
+    let mut line = 1;
+    let mut expect_key = false;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            '"' => {
+                let mut text = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        c => text.push(c),
+                    }
+                }
+                match stack.last_mut() {
+                    Some(Frame::Object(key)) if expect_key => *key = Some(text),
+                    _ => {
+                        lines.insert(path(&stack), line);
+                    }
+                }
+            }
+            ':' => expect_key = false,
+            ',' => match stack.last_mut() {
+                Some(Frame::Object(key)) => {
+                    *key = None;
+                    expect_key = true;
+                }
+                Some(Frame::Array(index)) => *index += 1,
+                None => {}
+            },
+            '{' | '[' => {
+                lines.insert(path(&stack), line);
+                if c == '{' {
+                    stack.push(Frame::Object(None));
+                    expect_key = true;
+                } else {
+                    stack.push(Frame::Array(0));
+                }
+            }
+            '}' | ']' => {
+                stack.pop();
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                lines.insert(path(&stack), line);
+                while chars
+                    .next_if(|c| !",}]".contains(*c) && !c.is_whitespace())
+                    .is_some()
+                {}
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_values_nested_in_arrays() {
+        let source = r#"{
+  "experience": [
+    { "company": "Acme", "start": "2020" },
+    {
+      "company": "Globex, \"Inc\"",
+      "start": "2021-03",
+      "technologies": ["Rust", "Go"],
+      "remote": true
+    }
+  ],
+  "count": 2
+}"#;
+        let lines = value_lines(source);
+        assert_eq!(lines[""], 1);
+        assert_eq!(lines["experience"], 2);
+        assert_eq!(lines["experience[0]"], 3);
+        assert_eq!(lines["experience[0].start"], 3);
+        assert_eq!(lines["experience[1]"], 4);
+        assert_eq!(lines["experience[1].company"], 5);
+        assert_eq!(lines["experience[1].start"], 6);
+        assert_eq!(lines["experience[1].technologies[1]"], 7);
+        assert_eq!(lines["experience[1].remote"], 8);
+        assert_eq!(lines["count"], 11);
+    }
+
+    #[test]
+    fn finds_top_level_arrays() {
+        let lines =
+            value_lines("[\n  { \"name\": \"a\" },\n  {\n    \"screenshots\": [\"x.png\"]\n  }\n]");
+        assert_eq!(lines["[0].name"], 2);
+        assert_eq!(lines["[1].screenshots[0]"], 4);
+    }
+}
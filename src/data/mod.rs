@@ -1,67 +1,34 @@
 use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
 
-const ABOUT: &str = include_str!("../datafile/about.json");
-const PROJECTS: &str = include_str!("../datafile/projects.json");
-const RESUME: &str = include_str!("../datafile/resume.json");
-const TERMS: &str = include_str!("../datafile/terms.json");
-const PRIVACY: &str = include_str!("../datafile/privacy.json");
+mod date;
+// Only build.rs uses it, it is part of the site for its tests
+#[cfg(test)]
+mod lines;
+mod types;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Profile {
-    pub name: String,
-    pub title: String,
-    pub email: Option<String>,
-    pub phone: Option<String>,
-    pub linkedin: Option<String>,
-    pub github: Option<String>,
-}
-
-impl Profile {
-    pub fn show_get_in_touch(&self) -> bool {
-        self.email.is_some() || self.linkedin.is_some() || self.github.is_some()
-    }
-}
+pub use date::{Date, DateEnd, DateError, Dates, Tenure, YearMonth};
+pub use types::*;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Experience {
-    pub position: String,
-    pub company: String,
-    pub duration: String,
-    pub description: Option<String>,
-    pub technologies: Vec<String>,
-}
+// Built from the datafiles by build.rs, which also validates them
+mod generated {
+    use super::*;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Education {
-    pub degree: String,
-    pub school: String,
-    pub year: Option<String>,
-    pub details: Option<String>,
+    include!(concat!(env!("OUT_DIR"), "/datafiles.rs"));
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SkillCategory {
-    pub name: String,
-    pub items: Vec<String>,
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Resume {
-    pub experience: Vec<Experience>,
-    pub education: Vec<Education>,
-    pub skills: Vec<SkillCategory>,
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Project {
-    pub name: String,
-    pub description: Option<String>,
-    pub keywords: Vec<String>,
-    pub website: Option<String>,
-    pub github: Option<String>,
-    pub image: Option<String>,
-    pub date: Option<String>,
-}
+static PROFILE: LazyLock<Profile> = LazyLock::new(generated::profile);
+static RESUME: LazyLock<Resume> = LazyLock::new(generated::resume);
+/// Newest first.
+static PROJECTS: LazyLock<Vec<Project>> = LazyLock::new(generated::projects);
+static ABOUT: LazyLock<About> = LazyLock::new(generated::about);
+#[cfg(feature = "legal-pages")]
+static TERMS: LazyLock<Terms> = LazyLock::new(generated::terms);
+#[cfg(feature = "legal-pages")]
+static PRIVACY: LazyLock<Privacy> = LazyLock::new(generated::privacy);
+static THEME: LazyLock<Theme> = LazyLock::new(generated::theme);
+/// Newest first.
+static POSTS: LazyLock<Vec<Post>> = LazyLock::new(generated::posts);
 
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContactForm {
@@ -69,61 +36,45 @@ pub struct ContactForm {
     pub email: String,
     pub phone: String,
     pub message: String,
+    /// A field hidden from visitors that only bots fill in, rejected by the contact API.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub website: String,
 }
 
-
-pub fn get_resume() -> Resume {
-    serde_json::from_str(RESUME).expect("valid json")
-}
-
-pub fn get_projects() -> Vec<Project> {
-    let mut projects: Vec<Project> = serde_json::from_str(PROJECTS).expect("valid json");
-    projects.sort_by(|a, b| b.date.cmp(&a.date));
-    projects
+pub fn get_profile() -> &'static Profile {
+    &PROFILE
 }
 
-pub fn get_about() -> About {
-    serde_json::from_str(ABOUT).expect("valid json")
+pub fn get_resume() -> &'static Resume {
+    &RESUME
 }
 
-pub fn get_terms() -> Terms {
-    serde_json::from_str(TERMS).expect("valid json")
+pub fn get_projects() -> &'static [Project] {
+    &PROJECTS
 }
 
-pub fn get_privacy() -> Privacy {
-    serde_json::from_str(PRIVACY).expect("valid json")
+pub fn get_project(slug: &str) -> Option<&'static Project> {
+    PROJECTS.iter().find(|project| project.slug() == slug)
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct AboutSection {
-    pub title: String,
-    pub content: String,
+pub fn get_about() -> &'static About {
+    &ABOUT
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct About {
-    pub intro: String,
-    pub sections: Vec<AboutSection>,
-    pub interests: Vec<String>,
-    pub values: Vec<String>,
+#[cfg(feature = "legal-pages")]
+pub fn get_terms() -> &'static Terms {
+    &TERMS
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct LegalSection {
-    pub title: String,
-    pub content: String,
+#[cfg(feature = "legal-pages")]
+pub fn get_privacy() -> &'static Privacy {
+    &PRIVACY
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Terms {
-    pub title: String,
-    pub effective_date: String,
-    pub sections: Vec<LegalSection>,
+pub fn get_theme() -> &'static Theme {
+    &THEME
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Privacy {
-    pub title: String,
-    pub effective_date: String,
-    pub sections: Vec<LegalSection>,
+pub fn get_posts() -> &'static [Post] {
+    &POSTS
 }
@@ -0,0 +1,406 @@
+//! Shapes of the datafiles.
+//!
+//! Kept free of anything but serde and the date types so `build.rs` can include this file and
+//! validate the datafiles with the very same definitions.
+
+use super::date::{Date, Dates};
+use serde::{Deserialize, Serialize};
+
+/// Css class and Font Awesome icon of the networks with their own look, by lowercase name.
+const NETWORKS: &[(&str, &str, &str)] = &[
+    ("linkedin", "linkedin", "fab fa-linkedin"),
+    ("github", "github", "fab fa-github"),
+    ("gitlab", "gitlab", "fab fa-gitlab"),
+    ("mastodon", "mastodon", "fab fa-mastodon"),
+    ("x", "x", "fab fa-x-twitter"),
+    ("twitter", "x", "fab fa-x-twitter"),
+    ("stack overflow", "stack-overflow", "fab fa-stack-overflow"),
+    ("stackoverflow", "stack-overflow", "fab fa-stack-overflow"),
+    ("blog", "blog", "fas fa-rss"),
+    ("website", "website", "fas fa-globe"),
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub title: String,
+    /// A sentence about what you do, shown under the title.
+    pub headline: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    /// Where you are based, e.g. `Cincinnati, OH`.
+    pub location: Option<String>,
+    pub linkedin: Option<String>,
+    pub github: Option<String>,
+    pub website: Option<String>,
+    /// Path under `assets/images` or a full url.
+    pub avatar: Option<String>,
+    /// Profiles beyond LinkedIn and GitHub, such as Mastodon or a blog.
+    #[serde(default)]
+    pub social: Vec<SocialLink>,
+}
+
+impl Profile {
+    /// First letter of each word of the name, shown when there is no avatar.
+    pub fn initials(&self) -> String {
+        self.name
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .collect::<String>()
+            .to_uppercase()
+    }
+
+    /// The city and the region of the location, reading `Cincinnati, OH` as both and a location
+    /// without a comma as just the city.
+    pub fn locality_region(&self) -> Option<(&str, Option<&str>)> {
+        let location = self.location.as_deref()?;
+        Some(match location.rsplit_once(',') {
+            Some((city, region)) => (city.trim(), Some(region.trim())),
+            None => (location.trim(), None),
+        })
+    }
+
+    pub fn show_get_in_touch(&self) -> bool {
+        self.email.is_some() || !self.social_links().is_empty()
+    }
+
+    /// LinkedIn, GitHub and the website followed by the other social links.
+    pub fn social_links(&self) -> Vec<SocialLink> {
+        [
+            ("LinkedIn", &self.linkedin),
+            ("GitHub", &self.github),
+            ("Website", &self.website),
+        ]
+        .into_iter()
+        .filter_map(|(network, url)| {
+            Some(SocialLink {
+                network: network.to_string(),
+                url: url.clone()?,
+                label: None,
+            })
+        })
+        .chain(self.social.iter().cloned())
+        .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SocialLink {
+    /// Name of the site, e.g. `Mastodon`, which also picks the icon.
+    pub network: String,
+    pub url: String,
+    /// Shown instead of the network name, e.g. a handle like `@dariusc93`.
+    pub label: Option<String>,
+}
+
+impl SocialLink {
+    fn known(&self) -> Option<&'static (&'static str, &'static str, &'static str)> {
+        let network = self.network.trim().to_lowercase();
+        NETWORKS.iter().find(|(name, _, _)| *name == network)
+    }
+
+    /// Css class for styling the link after its network, `link` for unknown networks.
+    pub fn class(&self) -> &'static str {
+        self.known().map_or("link", |(_, class, _)| class)
+    }
+
+    pub fn icon(&self) -> &'static str {
+        self.known().map_or("fas fa-link", |(_, _, icon)| icon)
+    }
+
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.network)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Experience {
+    pub position: String,
+    pub company: String,
+    #[serde(flatten)]
+    pub dates: Dates,
+    pub description: Option<String>,
+    pub technologies: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Education {
+    pub degree: String,
+    pub school: String,
+    #[serde(flatten)]
+    pub dates: Dates,
+    pub details: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SkillCategory {
+    pub name: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Resume {
+    pub experience: Vec<Experience>,
+    pub education: Vec<Education>,
+    pub skills: Vec<SkillCategory>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub name: String,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub website: Option<String>,
+    pub github: Option<String>,
+    pub image: Option<String>,
+    #[serde(flatten)]
+    pub dates: Dates,
+    /// Listed ahead of the rest when sorting by featured.
+    #[serde(default)]
+    pub featured: bool,
+    /// Long-form case study, written in markdown.
+    pub body: Option<String>,
+    pub role: Option<String>,
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub screenshots: Vec<String>,
+}
+
+impl Project {
+    /// The preview image followed by the screenshots.
+    pub fn images(&self) -> Vec<String> {
+        let mut images = Vec::with_capacity(self.screenshots.len() + 1);
+        for image in self.image.iter().chain(&self.screenshots) {
+            if !images.contains(image) {
+                images.push(image.clone());
+            }
+        }
+        images
+    }
+
+    /// Url segment of the project's detail page, derived from its name.
+    pub fn slug(&self) -> String {
+        slugify(&self.name)
+    }
+
+    /// Projects sharing at least one keyword with this one, most shared keywords first.
+    pub fn related<'a>(&self, projects: &'a [Project], limit: usize) -> Vec<&'a Project> {
+        let mut related = projects
+            .iter()
+            .filter(|project| project.name != self.name)
+            .map(|project| {
+                let shared = project
+                    .keywords
+                    .iter()
+                    .filter(|keyword| self.keywords.contains(keyword))
+                    .count();
+                (shared, project)
+            })
+            .filter(|(shared, _)| *shared > 0)
+            .collect::<Vec<_>>();
+        // Stable, so ties keep the newest-first order of `projects`
+        related.sort_by_key(|(shared, _)| std::cmp::Reverse(*shared));
+        related
+            .into_iter()
+            .take(limit)
+            .map(|(_, project)| project)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AboutSection {
+    pub title: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct About {
+    pub intro: String,
+    pub sections: Vec<AboutSection>,
+    pub interests: Vec<String>,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LegalSection {
+    pub title: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Terms {
+    pub title: String,
+    pub effective_date: String,
+    pub sections: Vec<LegalSection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Privacy {
+    pub title: String,
+    pub effective_date: String,
+    pub sections: Vec<LegalSection>,
+}
+
+/// Look of the site, so it can be rebranded without touching the code.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    pub dark: Palette,
+    pub light: Palette,
+    pub spacing: Spacing,
+    pub radii: Radii,
+    pub breakpoints: Breakpoints,
+}
+
+/// Colors of one theme, each a `#rrggbb` hex value.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Palette {
+    /// Any css background for the page, such as a gradient.
+    pub background: String,
+    pub surface: String,
+    pub surface_raised: String,
+    pub border: String,
+    pub heading: String,
+    pub text: String,
+    pub text_secondary: String,
+    pub text_muted: String,
+    pub text_subtle: String,
+    pub primary: String,
+    pub secondary: String,
+    pub link: String,
+    pub link_strong: String,
+    pub accent: String,
+    pub success: String,
+    pub success_text: String,
+    pub warning: String,
+    pub danger: String,
+    /// Laid over the background at a low alpha for glass panels and hover fills.
+    pub overlay: String,
+    pub shadow: String,
+    pub toast: String,
+}
+
+impl Palette {
+    /// Every color but the background, named like its `--color-*` custom property.
+    pub fn colors(&self) -> [(&'static str, &str); 20] {
+        [
+            ("surface", &self.surface),
+            ("surface-raised", &self.surface_raised),
+            ("border", &self.border),
+            ("heading", &self.heading),
+            ("text", &self.text),
+            ("text-secondary", &self.text_secondary),
+            ("text-muted", &self.text_muted),
+            ("text-subtle", &self.text_subtle),
+            ("primary", &self.primary),
+            ("secondary", &self.secondary),
+            ("link", &self.link),
+            ("link-strong", &self.link_strong),
+            ("accent", &self.accent),
+            ("success", &self.success),
+            ("success-text", &self.success_text),
+            ("warning", &self.warning),
+            ("danger", &self.danger),
+            ("overlay", &self.overlay),
+            ("shadow", &self.shadow),
+            ("toast", &self.toast),
+        ]
+    }
+}
+
+/// Css lengths, smallest first.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spacing {
+    pub xs: String,
+    pub sm: String,
+    pub md: String,
+    pub lg: String,
+    pub xl: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Radii {
+    pub sm: String,
+    pub md: String,
+    pub lg: String,
+    /// For fully rounded ends, as on tags.
+    pub pill: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Breakpoints {
+    /// Widest viewport in pixels that gets the single column layout.
+    pub mobile: u32,
+}
+
+/// Red, green and blue of a `#rgb` or `#rrggbb` color.
+pub fn hex_rgb(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    let channel = |index: usize, len: usize| {
+        let value = u8::from_str_radix(hex.get(index * len..(index + 1) * len)?, 16).ok()?;
+        Some(if len == 1 { value * 17 } else { value })
+    };
+    let len = match hex.len() {
+        3 => 1,
+        6 => 2,
+        _ => return None,
+    };
+    Some([channel(0, len)?, channel(1, len)?, channel(2, len)?])
+}
+
+/// Lowercase url segment for `text`, with every run of other characters turned into a `-`.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// The front matter opening a post in `datafile/posts`, between two `---` lines.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrontMatter {
+    pub title: String,
+    pub date: Date,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub summary: String,
+}
+
+/// A blog post, rendered from its markdown when the site is built.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Post {
+    /// Url segment of the post's page, the name of its file.
+    pub slug: String,
+    pub title: String,
+    pub date: Date,
+    pub tags: Vec<String>,
+    pub summary: String,
+    /// The body as html, with its code blocks highlighted.
+    pub html: String,
+    pub reading_minutes: u32,
+}
+
+impl Post {
+    pub fn has_tag(&self, slug: &str) -> bool {
+        self.tags.iter().any(|tag| slugify(tag) == slug)
+    }
+
+    /// Every tag used by `posts` with how many posts use it, most used first.
+    pub fn tags(posts: &[Post]) -> Vec<(&str, usize)> {
+        let mut tags = Vec::<(&str, usize)>::new();
+        for tag in posts.iter().flat_map(|post| &post.tags) {
+            match tags.iter_mut().find(|(name, _)| slugify(name) == slugify(tag)) {
+                Some((_, count)) => *count += 1,
+                None => tags.push((tag, 1)),
+            }
+        }
+        // Stable, so ties keep the order the tags first appear in
+        tags.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        tags
+    }
+}
@@ -0,0 +1,329 @@
+//! Feeds of the projects and posts in [RSS 2.0](https://www.rssboard.org/rss-specification),
+//! [Atom](https://www.rfc-editor.org/rfc/rfc4287) and [JSON Feed](https://www.jsonfeed.org/version/1.1/).
+//!
+//! Feed readers need absolute urls, so there are only feeds when the profile has a `website`.
+
+use crate::data::{Date, Post, Profile, Project, YearMonth};
+use crate::head::escape;
+use crate::markdown;
+use crate::Route;
+use serde::Serialize;
+use std::fmt::Write;
+use yew_router::Routable;
+
+const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl Format {
+    pub const ALL: [Format; 3] = [Format::Rss, Format::Atom, Format::Json];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Format::Rss => "feed.xml",
+            Format::Atom => "atom.xml",
+            Format::Json => "feed.json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Format::Rss => "application/rss+xml",
+            Format::Atom => "application/atom+xml",
+            Format::Json => "application/feed+json",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Format::Rss => "RSS",
+            Format::Atom => "Atom",
+            Format::Json => "JSON Feed",
+        }
+    }
+}
+
+/// Where each feed is published, for the autodiscovery links in the head of every page.
+pub fn urls(profile: &Profile) -> Vec<(Format, String)> {
+    let Some(website) = &profile.website else {
+        return Vec::new();
+    };
+    let website = website.trim_end_matches('/');
+    Format::ALL
+        .into_iter()
+        .map(|format| (format, format!("{website}/{}", format.file_name())))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeedItem {
+    pub title: String,
+    pub url: String,
+    pub summary: String,
+    /// The full text, for posts.
+    pub content_html: Option<String>,
+    pub date: Option<Date>,
+    pub categories: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    pub website: String,
+    pub author: String,
+    /// Newest first, undated items last.
+    pub items: Vec<FeedItem>,
+    /// Date of the newest item.
+    pub updated: Date,
+}
+
+impl Feed {
+    /// The posts and projects as one feed, or `None` without a website to resolve urls against.
+    pub fn new(profile: &Profile, projects: &[Project], posts: &[Post]) -> Option<Self> {
+        let website = profile
+            .website
+            .as_deref()?
+            .trim_end_matches('/')
+            .to_string();
+        let url = |route: Route| format!("{website}{}", route.to_path());
+
+        let posts = posts.iter().map(|post| FeedItem {
+            title: post.title.clone(),
+            url: url(Route::Post {
+                slug: post.slug.clone(),
+            }),
+            summary: post.summary.clone(),
+            content_html: Some(post.html.clone()),
+            date: Some(post.date),
+            categories: post.tags.clone(),
+        });
+        // A project appears in the feed when it was started or released
+        let projects = projects.iter().map(|project| FeedItem {
+            title: project.name.clone(),
+            url: url(Route::ProjectDetail {
+                slug: project.slug(),
+            }),
+            summary: project
+                .description
+                .as_deref()
+                .map(markdown::plain_text)
+                .unwrap_or_else(|| format!("{}: {}", project.name, project.keywords.join(", "))),
+            content_html: None,
+            date: project.dates.start.map(|start| start.first_day()),
+            categories: project.keywords.clone(),
+        });
+
+        let mut items = posts.chain(projects).collect::<Vec<_>>();
+        // Stable, so items from the same day keep posts before projects
+        items.sort_by_key(|item| std::cmp::Reverse(item.date));
+        let updated = items
+            .iter()
+            .find_map(|item| item.date)
+            .unwrap_or_else(|| YearMonth::now().first_day());
+
+        Some(Self {
+            title: profile.name.clone(),
+            description: format!("Projects and posts by {}, {}.", profile.name, profile.title),
+            website,
+            author: profile.name.clone(),
+            items,
+            updated,
+        })
+    }
+
+    fn self_url(&self, format: Format) -> String {
+        format!("{}/{}", self.website, format.file_name())
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Rss => self.rss(),
+            Format::Atom => self.atom(),
+            Format::Json => self.json(),
+        }
+    }
+
+    fn rss(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        xml.push_str(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom"><channel>"#);
+        write!(
+            xml,
+            "<title>{}</title><link>{}/</link><description>{}</description><lastBuildDate>{}</lastBuildDate>",
+            escape(&self.title),
+            escape(&self.website),
+            escape(&self.description),
+            rfc822(self.updated),
+        )
+        .unwrap();
+        write!(
+            xml,
+            r#"<atom:link href="{}" rel="self" type="{}"/>"#,
+            escape(&self.self_url(Format::Rss)),
+            Format::Rss.mime_type(),
+        )
+        .unwrap();
+
+        for item in &self.items {
+            write!(
+                xml,
+                r#"<item><title>{}</title><link>{url}</link><guid isPermaLink="true">{url}</guid><description>{}</description>"#,
+                escape(&item.title),
+                escape(&item.summary),
+                url = escape(&item.url),
+            )
+            .unwrap();
+            if let Some(date) = item.date {
+                write!(xml, "<pubDate>{}</pubDate>", rfc822(date)).unwrap();
+            }
+            for category in &item.categories {
+                write!(xml, "<category>{}</category>", escape(category)).unwrap();
+            }
+            xml.push_str("</item>");
+        }
+
+        xml.push_str("</channel></rss>\n");
+        xml
+    }
+
+    fn atom(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+        write!(
+            xml,
+            r#"<id>{website}/</id><title>{}</title><subtitle>{}</subtitle><updated>{}</updated><link href="{website}/"/><link href="{}" rel="self" type="{}"/><author><name>{}</name></author>"#,
+            escape(&self.title),
+            escape(&self.description),
+            rfc3339(self.updated),
+            escape(&self.self_url(Format::Atom)),
+            Format::Atom.mime_type(),
+            escape(&self.author),
+            website = escape(&self.website),
+        )
+        .unwrap();
+
+        for item in &self.items {
+            // Atom requires a date, so undated items take the feed's
+            let updated = rfc3339(item.date.unwrap_or(self.updated));
+            write!(
+                xml,
+                r#"<entry><id>{url}</id><title>{}</title><link href="{url}"/><updated>{updated}</updated><summary>{}</summary>"#,
+                escape(&item.title),
+                escape(&item.summary),
+                url = escape(&item.url),
+            )
+            .unwrap();
+            if item.date.is_some() {
+                write!(xml, "<published>{updated}</published>").unwrap();
+            }
+            if let Some(content) = &item.content_html {
+                write!(xml, r#"<content type="html">{}</content>"#, escape(content)).unwrap();
+            }
+            for category in &item.categories {
+                write!(xml, r#"<category term="{}"/>"#, escape(category)).unwrap();
+            }
+            xml.push_str("</entry>");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    fn json(&self) -> String {
+        #[derive(Serialize)]
+        struct JsonFeed<'a> {
+            version: &'static str,
+            title: &'a str,
+            home_page_url: String,
+            feed_url: String,
+            description: &'a str,
+            authors: [JsonAuthor<'a>; 1],
+            items: Vec<JsonItem<'a>>,
+        }
+
+        #[derive(Serialize)]
+        struct JsonAuthor<'a> {
+            name: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct JsonItem<'a> {
+            id: &'a str,
+            url: &'a str,
+            title: &'a str,
+            summary: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content_html: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content_text: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            date_published: Option<String>,
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            tags: &'a [String],
+        }
+
+        let feed = JsonFeed {
+            version: "https://jsonfeed.org/version/1.1",
+            title: &self.title,
+            home_page_url: format!("{}/", self.website),
+            feed_url: self.self_url(Format::Json),
+            description: &self.description,
+            authors: [JsonAuthor { name: &self.author }],
+            items: self
+                .items
+                .iter()
+                .map(|item| JsonItem {
+                    id: &item.url,
+                    url: &item.url,
+                    title: &item.title,
+                    summary: &item.summary,
+                    content_html: item.content_html.as_deref(),
+                    // Every item needs content of some kind
+                    content_text: item.content_html.is_none().then_some(item.summary.as_str()),
+                    date_published: item.date.map(rfc3339),
+                    tags: &item.categories,
+                })
+                .collect(),
+        };
+        let mut json = serde_json::to_string_pretty(&feed).expect("feed serializes");
+        json.push('\n');
+        json
+    }
+}
+
+/// `Sun, 11 Feb 2024 00:00:00 +0000`, the date format of RSS.
+fn rfc822(date: Date) -> String {
+    format!(
+        "{}, {} 00:00:00 +0000",
+        DAYS[usize::from(date.weekday())],
+        date.label()
+    )
+}
+
+/// `2024-02-11T00:00:00Z`, the date format of Atom and JSON Feed.
+fn rfc3339(date: Date) -> String {
+    format!("{date}T00:00:00Z")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_rfc822_with_the_day_of_week() {
+        assert_eq!(
+            rfc822(Date::new(2024, 2, 29)),
+            "Thu, 29 Feb 2024 00:00:00 +0000"
+        );
+    }
+
+    #[test]
+    fn formats_rfc3339() {
+        assert_eq!(rfc3339(Date::new(2024, 3, 5)), "2024-03-05T00:00:00Z");
+    }
+}
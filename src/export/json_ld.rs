@@ -0,0 +1,275 @@
+//! [schema.org](https://schema.org) descriptions of the profile, resume and projects, embedded as
+//! JSON-LD in the document head so search engines can tell who the site is about.
+
+use crate::data::{DateEnd, Dates, Education, Experience, Profile, Project, Resume};
+use crate::{images, markdown, Route};
+use serde::Serialize;
+use yew_router::Routable;
+
+pub const MIME_TYPE: &str = "application/ld+json";
+const CONTEXT: &str = "https://schema.org";
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telephone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<PostalAddress>,
+    /// Profiles on other sites.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub same_as: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub works_for: Vec<OrganizationRole>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub has_credential: Vec<Credential>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub knows_about: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostalAddress {
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    pub address_locality: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_region: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Organization {
+    /// `Organization`, or `EducationalOrganization` for schools.
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    pub name: String,
+}
+
+/// A position held at a company, wrapping the company the way schema.org roles wrap the value of
+/// the property they qualify.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationRole {
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    pub role_name: String,
+    pub works_for: Organization,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Left out while the role is ongoing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A degree, as an `EducationalOccupationalCredential`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Credential {
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    pub name: String,
+    pub credential_category: &'static str,
+    pub recognized_by: Organization,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreativeWork {
+    #[serde(rename = "@type")]
+    pub kind: &'static str,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    /// The project's own website and repository.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub same_as: Vec<String>,
+    pub creator: Person,
+}
+
+/// Several descriptions in one script.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Graph<T> {
+    #[serde(rename = "@graph")]
+    pub items: Vec<T>,
+}
+
+#[derive(Serialize)]
+struct Document<'a, T> {
+    #[serde(rename = "@context")]
+    context: &'static str,
+    #[serde(flatten)]
+    item: &'a T,
+}
+
+fn website(profile: &Profile) -> Option<&str> {
+    profile
+        .website
+        .as_deref()
+        .map(|website| website.trim_end_matches('/'))
+}
+
+fn start_date(dates: &Dates) -> Option<String> {
+    dates.start.map(|start| start.to_string())
+}
+
+fn end_date(dates: &Dates) -> Option<String> {
+    match dates.end {
+        Some(DateEnd::On(end)) => Some(end.to_string()),
+        Some(DateEnd::Present) | None => None,
+    }
+}
+
+/// Just enough of the person to point at the full description on the other pages.
+fn reference(profile: &Profile) -> Person {
+    Person {
+        kind: "Person",
+        id: website(profile).map(|website| format!("{website}/#person")),
+        name: profile.name.clone(),
+        job_title: None,
+        description: None,
+        url: None,
+        image: None,
+        email: None,
+        telephone: None,
+        address: None,
+        same_as: Vec::new(),
+        works_for: Vec::new(),
+        has_credential: Vec::new(),
+        knows_about: Vec::new(),
+    }
+}
+
+fn role(experience: &Experience) -> OrganizationRole {
+    OrganizationRole {
+        kind: "OrganizationRole",
+        role_name: experience.position.clone(),
+        works_for: Organization {
+            kind: "Organization",
+            name: experience.company.clone(),
+        },
+        start_date: start_date(&experience.dates),
+        end_date: end_date(&experience.dates),
+        description: experience.description.as_deref().map(markdown::plain_text),
+    }
+}
+
+fn credential(education: &Education) -> Credential {
+    Credential {
+        kind: "EducationalOccupationalCredential",
+        name: education.degree.clone(),
+        credential_category: "degree",
+        recognized_by: Organization {
+            kind: "EducationalOrganization",
+            name: education.school.clone(),
+        },
+        description: education.details.clone(),
+    }
+}
+
+/// The profile along with the roles, degrees and skills of the resume.
+pub fn person(profile: &Profile, resume: &Resume) -> Person {
+    let website = website(profile);
+    Person {
+        job_title: Some(profile.title.clone()),
+        description: profile.headline.clone(),
+        url: website.map(|website| format!("{website}/")),
+        image: profile
+            .avatar
+            .as_deref()
+            .map(|avatar| images::absolute_url(avatar, website)),
+        email: profile.email.clone(),
+        telephone: profile.phone.clone(),
+        address: profile
+            .locality_region()
+            .map(|(locality, region)| PostalAddress {
+                kind: "PostalAddress",
+                address_locality: locality.to_string(),
+                address_region: region.map(str::to_string),
+            }),
+        same_as: profile
+            .social_links()
+            .into_iter()
+            .map(|link| link.url)
+            .filter(|url| Some(url.trim_end_matches('/')) != website)
+            .collect(),
+        works_for: resume.experience.iter().map(role).collect(),
+        has_credential: resume.education.iter().map(credential).collect(),
+        knows_about: resume
+            .skills
+            .iter()
+            .flat_map(|category| category.items.iter().cloned())
+            .collect(),
+        ..reference(profile)
+    }
+}
+
+pub fn creative_work(profile: &Profile, project: &Project) -> CreativeWork {
+    let website = website(profile);
+    let url = website.map(|website| {
+        let route = Route::ProjectDetail {
+            slug: project.slug(),
+        };
+        format!("{website}{}", route.to_path())
+    });
+    CreativeWork {
+        kind: "CreativeWork",
+        id: url.clone(),
+        name: project.name.clone(),
+        description: project.description.as_deref().map(markdown::plain_text),
+        url,
+        image: project
+            .image
+            .as_deref()
+            .map(|image| images::absolute_url(image, website)),
+        keywords: (!project.keywords.is_empty()).then(|| project.keywords.join(", ")),
+        date_created: start_date(&project.dates),
+        same_as: project
+            .website
+            .iter()
+            .chain(&project.github)
+            .cloned()
+            .collect(),
+        creator: reference(profile),
+    }
+}
+
+/// The JSON-LD for a `<script type="application/ld+json">`, escaped so the text of the data can't
+/// close the script early.
+pub fn to_script<T: Serialize>(item: &T) -> String {
+    let document = Document {
+        context: CONTEXT,
+        item,
+    };
+    serde_json::to_string(&document)
+        .expect("JSON-LD serializes")
+        .replace("</", "<\\/")
+}
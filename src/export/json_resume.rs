@@ -0,0 +1,776 @@
+//! Conversion to and from the [JSON Resume](https://jsonresume.org/schema) format.
+//!
+//! Only the sections with a counterpart in the datafiles are mapped: `basics`, `work`,
+//! `education`, `skills`, `projects` and `interests`. Anything else is kept on the way in so it
+//! can be listed in a [`MappingReport`] rather than dropped silently.
+
+use crate::data::{
+    About, DateEnd, Dates, Education, Experience, Profile, Project, Resume, SkillCategory,
+    SocialLink, YearMonth,
+};
+use crate::images;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+pub const MIME_TYPE: &str = "application/json";
+pub const FILE_NAME: &str = "resume.json";
+pub const SCHEMA: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResume {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(default)]
+    pub basics: Basics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<JsonEducation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<Skill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<JsonProject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interests: Vec<Interest>,
+    /// Sections without a counterpart, such as `volunteer` or `awards`.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basics {
+    #[serde(default)]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<SocialProfile>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SocialProfile {
+    #[serde(default)]
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work {
+    /// Name of the company.
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonEducation {
+    #[serde(default)]
+    pub institution: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Skill {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonProject {
+    #[serde(default)]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interest {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Fields that had a value but no place to go in the other format, as paths like
+/// `work[2].highlights` or `experience[0].technologies`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MappingReport {
+    pub unmapped: Vec<String>,
+}
+
+impl MappingReport {
+    pub fn is_empty(&self) -> bool {
+        self.unmapped.is_empty()
+    }
+
+    fn skip(&mut self, path: impl Into<String>) {
+        self.unmapped.push(path.into());
+    }
+
+    fn skip_if(&mut self, present: bool, path: impl Into<String>) {
+        if present {
+            self.skip(path);
+        }
+    }
+
+    fn skip_other(&mut self, path: &str, other: &Map<String, Value>) {
+        for (key, value) in other {
+            let empty = match value {
+                Value::Null => true,
+                Value::String(value) => value.is_empty(),
+                Value::Array(values) => values.is_empty(),
+                Value::Object(values) => values.is_empty(),
+                _ => false,
+            };
+            if !empty {
+                self.skip(match path {
+                    "" => key.clone(),
+                    path => format!("{path}.{key}"),
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for MappingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for path in &self.unmapped {
+            writeln!(f, "{path}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Everything read from a JSON Resume. The summary and interests belong in `about.json`, whose
+/// other fields have no JSON Resume counterpart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Imported {
+    pub profile: Profile,
+    pub resume: Resume,
+    pub projects: Vec<Project>,
+    pub summary: Option<String>,
+    pub interests: Vec<String>,
+}
+
+impl Imported {
+    /// Carries over what JSON Resume has nowhere to put from the current datafiles, for entries
+    /// that are still there.
+    pub fn keep_local_fields(&mut self, profile: &Profile, resume: &Resume, projects: &[Project]) {
+        self.profile.headline = profile.headline.clone();
+        // The export turns the avatar into a full url, which would replace the local image path
+        self.profile.avatar = profile.avatar.clone().or_else(|| self.profile.avatar.take());
+        for link in &mut self.profile.social {
+            if let Some(existing) = profile
+                .social
+                .iter()
+                .find(|existing| existing.network == link.network && existing.url == link.url)
+            {
+                link.label = existing.label.clone();
+            }
+        }
+
+        for experience in &mut self.resume.experience {
+            if let Some(existing) = resume.experience.iter().find(|existing| {
+                existing.company == experience.company && existing.position == experience.position
+            }) {
+                experience.technologies = existing.technologies.clone();
+                keep_dates(&mut experience.dates, &existing.dates);
+            }
+        }
+        for education in &mut self.resume.education {
+            if let Some(existing) = resume
+                .education
+                .iter()
+                .find(|existing| existing.school == education.school)
+            {
+                education.details = existing.details.clone();
+                keep_dates(&mut education.dates, &existing.dates);
+            }
+        }
+
+        for project in &mut self.projects {
+            if let Some(existing) = projects
+                .iter()
+                .find(|existing| existing.slug() == project.slug())
+            {
+                project.website = project.website.take().or_else(|| existing.website.clone());
+                project.github = project.github.take().or_else(|| existing.github.clone());
+                project.image = existing.image.clone();
+                project.screenshots = existing.screenshots.clone();
+                project.featured = existing.featured;
+                project.body = existing.body.clone();
+                project.outcome = existing.outcome.clone();
+                keep_dates(&mut project.dates, &existing.dates);
+            }
+        }
+    }
+}
+
+/// JSON Resume can't tell a range ending where it starts, like `2016 - 2016`, from a single point
+/// in time, so the current dates stay when they are what the imported ones were exported from.
+fn keep_dates(imported: &mut Dates, existing: &Dates) {
+    if start_date(imported) == start_date(existing) && end_date(imported) == end_date(existing) {
+        *imported = *existing;
+    }
+}
+
+fn start_date(dates: &Dates) -> Option<String> {
+    dates.start.map(|start| start.to_string())
+}
+
+/// JSON Resume leaves out the end date of anything ongoing.
+fn end_date(dates: &Dates) -> Option<String> {
+    match dates.end {
+        Some(DateEnd::On(end)) => Some(end.to_string()),
+        Some(DateEnd::Present) => None,
+        None => start_date(dates),
+    }
+}
+
+fn parse_dates(
+    report: &mut MappingReport,
+    path: &str,
+    start: Option<&str>,
+    end: Option<&str>,
+    ongoing: bool,
+) -> Dates {
+    let mut parse = |field: &str, value: Option<&str>| {
+        let value = value.filter(|value| !value.trim().is_empty())?;
+        let parsed = value.parse::<YearMonth>().ok();
+        report.skip_if(parsed.is_none(), format!("{path}.{field}"));
+        parsed
+    };
+    let ongoing = ongoing && end.is_none_or(|end| end.trim().is_empty());
+    let start = parse("startDate", start);
+    let end = parse("endDate", end);
+    Dates {
+        start,
+        end: match (start, end) {
+            (_, Some(end)) if Some(end) != start => Some(DateEnd::On(end)),
+            (Some(_), None) if ongoing => Some(DateEnd::Present),
+            _ => None,
+        },
+    }
+}
+
+/// Username of a profile url, e.g. `dariusc93` for `https://github.com/dariusc93`.
+fn username(url: &str) -> Option<String> {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|segment| !segment.is_empty() && !segment.contains(':'))
+        .map(str::to_string)
+}
+
+fn is_github(url: &str) -> bool {
+    url.split("://")
+        .nth(1)
+        .unwrap_or(url)
+        .trim_start_matches("www.")
+        .starts_with("github.com/")
+}
+
+/// Converts the datafiles to a JSON Resume, reporting the fields it has no place for.
+pub fn export(
+    profile: &Profile,
+    about: &About,
+    resume: &Resume,
+    projects: &[Project],
+) -> (JsonResume, MappingReport) {
+    let mut report = MappingReport::default();
+
+    let image = profile
+        .avatar
+        .as_deref()
+        .map(|avatar| images::absolute_url(avatar, profile.website.as_deref()));
+    let location = profile.locality_region().map(|(city, region)| Location {
+        city: Some(city.to_string()),
+        region: region.map(str::to_string),
+        country_code: None,
+        other: Map::new(),
+    });
+    let basics = Basics {
+        name: profile.name.clone(),
+        label: Some(profile.title.clone()),
+        email: profile.email.clone(),
+        phone: profile.phone.clone(),
+        url: profile.website.clone(),
+        image,
+        summary: Some(about.intro.clone()),
+        location,
+        profiles: profile
+            .social_links()
+            .into_iter()
+            .filter(|link| Some(&link.url) != profile.website.as_ref())
+            .map(|link| SocialProfile {
+                username: link
+                    .label
+                    .as_ref()
+                    .map(|label| label.trim_start_matches('@').to_string())
+                    .or_else(|| username(&link.url)),
+                network: link.network,
+                url: Some(link.url),
+                other: Map::new(),
+            })
+            .collect(),
+        other: Map::new(),
+    };
+    report.skip_if(profile.headline.is_some(), "profile.headline");
+    report.skip_if(!about.sections.is_empty(), "about.sections");
+    report.skip_if(!about.values.is_empty(), "about.values");
+
+    let work = resume
+        .experience
+        .iter()
+        .enumerate()
+        .map(|(index, experience)| {
+            report.skip_if(
+                !experience.technologies.is_empty(),
+                format!("experience[{index}].technologies"),
+            );
+            Work {
+                name: experience.company.clone(),
+                position: experience.position.clone(),
+                start_date: start_date(&experience.dates),
+                end_date: end_date(&experience.dates),
+                summary: experience.description.clone(),
+                other: Map::new(),
+            }
+        })
+        .collect();
+
+    let education = resume
+        .education
+        .iter()
+        .enumerate()
+        .map(|(index, education)| {
+            report.skip_if(
+                education.details.is_some(),
+                format!("education[{index}].details"),
+            );
+            // "Bachelor of Science in Computer Science" is split into the type and the area
+            let (study_type, area) = match education.degree.split_once(" in ") {
+                Some((study_type, area)) => (study_type.to_string(), Some(area.to_string())),
+                None => (education.degree.clone(), None),
+            };
+            JsonEducation {
+                institution: education.school.clone(),
+                area,
+                study_type: Some(study_type),
+                start_date: start_date(&education.dates),
+                end_date: end_date(&education.dates),
+                other: Map::new(),
+            }
+        })
+        .collect();
+
+    let skills = resume
+        .skills
+        .iter()
+        .map(|category| Skill {
+            name: category.name.clone(),
+            keywords: category.items.clone(),
+            other: Map::new(),
+        })
+        .collect();
+
+    let projects = projects
+        .iter()
+        .enumerate()
+        .map(|(index, project)| {
+            let path = |field: &str| format!("projects[{index}].{field}");
+            // Only one url fits, so the website wins over the code
+            report.skip_if(
+                project.website.is_some() && project.github.is_some(),
+                path("github"),
+            );
+            report.skip_if(project.image.is_some(), path("image"));
+            report.skip_if(!project.screenshots.is_empty(), path("screenshots"));
+            report.skip_if(project.body.is_some(), path("body"));
+            report.skip_if(project.outcome.is_some(), path("outcome"));
+            report.skip_if(project.featured, path("featured"));
+            JsonProject {
+                name: project.name.clone(),
+                description: project.description.clone(),
+                keywords: project.keywords.clone(),
+                url: project.website.clone().or_else(|| project.github.clone()),
+                roles: project.role.iter().cloned().collect(),
+                start_date: start_date(&project.dates),
+                end_date: match project.dates.end {
+                    None => None,
+                    Some(_) => end_date(&project.dates),
+                },
+                other: Map::new(),
+            }
+        })
+        .collect();
+
+    let interests = about
+        .interests
+        .iter()
+        .map(|name| Interest {
+            name: name.clone(),
+            keywords: Vec::new(),
+            other: Map::new(),
+        })
+        .collect();
+
+    let json = JsonResume {
+        schema: Some(SCHEMA.to_string()),
+        basics,
+        work,
+        education,
+        skills,
+        projects,
+        interests,
+        other: Map::new(),
+    };
+    (json, report)
+}
+
+/// Converts a JSON Resume to the datafile shapes, reporting the fields it has no place for.
+pub fn import(json: &JsonResume) -> (Imported, MappingReport) {
+    let mut report = MappingReport::default();
+    report.skip_other("", &json.other);
+
+    let basics = &json.basics;
+    report.skip_other("basics", &basics.other);
+    let location = basics.location.as_ref().and_then(|location| {
+        report.skip_other("basics.location", &location.other);
+        let parts = [&location.city, &location.region]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        match (parts.is_empty(), &location.country_code) {
+            (false, _) => Some(parts.join(", ")),
+            (true, country_code) => country_code.clone(),
+        }
+    });
+    let mut profile = Profile {
+        name: basics.name.clone(),
+        title: basics.label.clone().unwrap_or_default(),
+        headline: None,
+        email: basics.email.clone(),
+        phone: basics.phone.clone(),
+        location,
+        linkedin: None,
+        github: None,
+        website: basics.url.clone(),
+        avatar: basics.image.clone(),
+        social: Vec::new(),
+    };
+    for (index, social) in basics.profiles.iter().enumerate() {
+        let path = format!("basics.profiles[{index}]");
+        let (slot, base) = match social.network.to_ascii_lowercase().as_str() {
+            "linkedin" => (Some(&mut profile.linkedin), "https://www.linkedin.com/in/"),
+            "github" => (Some(&mut profile.github), "https://github.com/"),
+            _ => (None, ""),
+        };
+        let url = social.url.clone().or_else(|| {
+            let username = social.username.as_ref().filter(|_| !base.is_empty())?;
+            Some(format!("{base}{username}"))
+        });
+        let Some(url) = url else {
+            report.skip(path);
+            continue;
+        };
+        report.skip_other(&path, &social.other);
+        match slot {
+            Some(slot) if slot.is_none() => *slot = Some(url),
+            _ => profile.social.push(SocialLink {
+                network: social.network.clone(),
+                url,
+                label: social.username.clone(),
+            }),
+        }
+    }
+
+    let experience = json
+        .work
+        .iter()
+        .enumerate()
+        .map(|(index, work)| {
+            let path = format!("work[{index}]");
+            report.skip_other(&path, &work.other);
+            Experience {
+                position: work.position.clone(),
+                company: work.name.clone(),
+                dates: parse_dates(
+                    &mut report,
+                    &path,
+                    work.start_date.as_deref(),
+                    work.end_date.as_deref(),
+                    true,
+                ),
+                description: work.summary.clone(),
+                technologies: Vec::new(),
+            }
+        })
+        .collect();
+
+    let education = json
+        .education
+        .iter()
+        .enumerate()
+        .map(|(index, education)| {
+            let path = format!("education[{index}]");
+            report.skip_other(&path, &education.other);
+            let degree = match (&education.study_type, &education.area) {
+                (Some(study_type), Some(area)) => format!("{study_type} in {area}"),
+                (Some(degree), None) | (None, Some(degree)) => degree.clone(),
+                (None, None) => String::new(),
+            };
+            Education {
+                degree,
+                school: education.institution.clone(),
+                dates: parse_dates(
+                    &mut report,
+                    &path,
+                    education.start_date.as_deref(),
+                    education.end_date.as_deref(),
+                    true,
+                ),
+                details: None,
+            }
+        })
+        .collect();
+
+    let skills = json
+        .skills
+        .iter()
+        .enumerate()
+        .map(|(index, skill)| {
+            report.skip_other(&format!("skills[{index}]"), &skill.other);
+            SkillCategory {
+                name: skill.name.clone(),
+                items: skill.keywords.clone(),
+            }
+        })
+        .collect();
+
+    let projects = json
+        .projects
+        .iter()
+        .enumerate()
+        .map(|(index, project)| {
+            let path = format!("projects[{index}]");
+            report.skip_other(&path, &project.other);
+            report.skip_if(project.roles.len() > 1, format!("{path}.roles"));
+            let (website, github) = match &project.url {
+                Some(url) if is_github(url) => (None, Some(url.clone())),
+                url => (url.clone(), None),
+            };
+            Project {
+                name: project.name.clone(),
+                description: project.description.clone(),
+                keywords: project.keywords.clone(),
+                website,
+                github,
+                image: None,
+                dates: parse_dates(
+                    &mut report,
+                    &path,
+                    project.start_date.as_deref(),
+                    project.end_date.as_deref(),
+                    false,
+                ),
+                featured: false,
+                body: None,
+                role: project.roles.first().cloned(),
+                outcome: None,
+                screenshots: Vec::new(),
+            }
+        })
+        .collect();
+
+    let interests = json
+        .interests
+        .iter()
+        .enumerate()
+        .map(|(index, interest)| {
+            let path = format!("interests[{index}]");
+            report.skip_if(!interest.keywords.is_empty(), format!("{path}.keywords"));
+            report.skip_other(&path, &interest.other);
+            interest.name.clone()
+        })
+        .collect();
+
+    let imported = Imported {
+        profile,
+        resume: Resume {
+            experience,
+            education,
+            skills,
+        },
+        projects,
+        summary: basics.summary.clone(),
+        interests,
+    };
+    (imported, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export, import, JsonResume};
+    use crate::data::{get_about, get_profile, get_projects, get_resume, Profile, SocialLink};
+
+    /// The bundled profile along with the fields JSON Resume can't hold as they are.
+    fn profile() -> Profile {
+        Profile {
+            headline: Some("Building peer-to-peer systems in Rust.".to_string()),
+            avatar: Some("avatar.png".to_string()),
+            social: vec![SocialLink {
+                network: "Mastodon".to_string(),
+                url: "https://hachyderm.io/@someone".to_string(),
+                label: Some("@someone".to_string()),
+            }],
+            ..get_profile().clone()
+        }
+    }
+
+    #[test]
+    fn round_trips_the_datafiles() {
+        let profile = profile();
+        let (json, _) = export(&profile, get_about(), get_resume(), get_projects());
+        let json = serde_json::to_string(&json).unwrap();
+        let json = serde_json::from_str::<JsonResume>(&json).unwrap();
+
+        let (mut imported, report) = import(&json);
+        assert!(report.is_empty(), "{report}");
+        imported.keep_local_fields(&profile, get_resume(), get_projects());
+
+        assert_eq!(imported.profile, profile);
+        assert_eq!(imported.resume, *get_resume());
+        assert_eq!(imported.projects, *get_projects());
+        assert_eq!(imported.summary.as_ref(), Some(&get_about().intro));
+        assert_eq!(imported.interests, get_about().interests);
+    }
+
+    #[test]
+    fn reports_what_json_resume_cannot_hold() {
+        let (_, report) = export(&profile(), get_about(), get_resume(), get_projects());
+        for path in ["profile.headline", "about.sections", "about.values"] {
+            assert!(
+                report.unmapped.iter().any(|unmapped| unmapped == path),
+                "{path}"
+            );
+        }
+        let technologies = get_resume()
+            .experience
+            .iter()
+            .enumerate()
+            .filter(|(_, experience)| !experience.technologies.is_empty())
+            .map(|(index, _)| format!("experience[{index}].technologies"));
+        for path in technologies {
+            assert!(report.unmapped.contains(&path), "{path}");
+        }
+
+        let json = serde_json::from_str::<JsonResume>(
+            r#"{
+                "basics": {
+                    "name": "Ada Lovelace",
+                    "summary": "Mathematician",
+                    "location": { "city": "London", "postalCode": "W1" },
+                    "profiles": [
+                        { "network": "Mastodon", "url": "https://hachyderm.io/@ada", "followers": 10 },
+                        { "network": "Mastodon", "username": "ada" }
+                    ]
+                },
+                "work": [{ "name": "Analytical Engine", "position": "Programmer", "startDate": "someday" }],
+                "awards": [{ "title": "First program" }],
+                "publications": []
+            }"#,
+        )
+        .unwrap();
+        let (_, report) = import(&json);
+        assert_eq!(
+            report.unmapped,
+            [
+                "awards",
+                "basics.location.postalCode",
+                "basics.profiles[0].followers",
+                "basics.profiles[1]",
+                "work[0].startDate",
+            ]
+        );
+    }
+}
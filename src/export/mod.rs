@@ -0,0 +1,26 @@
+pub mod feed;
+pub mod json_ld;
+pub mod json_resume;
+pub mod pdf;
+pub mod vcard;
+
+use gloo::file::{Blob, ObjectUrl};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlAnchorElement;
+
+/// Hands `bytes` to the browser as a file download named `file_name`.
+pub fn download(file_name: &str, mime_type: &str, bytes: &[u8]) {
+    let blob = Blob::new_with_options(bytes, Some(mime_type));
+    let url = ObjectUrl::from(blob);
+
+    let anchor: HtmlAnchorElement = gloo::utils::document()
+        .create_element("a")
+        .expect("Failed to create anchor")
+        .unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // Keep the object url alive long enough for the browser to start the download
+    gloo::timers::callback::Timeout::new(1000, move || drop(url)).forget();
+}
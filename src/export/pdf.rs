@@ -0,0 +1,479 @@
+use crate::data::{Education, Experience, Profile, Resume, SkillCategory};
+use crate::markdown;
+
+pub const MIME_TYPE: &str = "application/pdf";
+
+// US Letter in points, laid out as a single ATS-friendly column of real text
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 54.0;
+const FOOTER_HEIGHT: f32 = 24.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+const BLACK: (f32, f32, f32) = (0.07, 0.09, 0.15);
+const GRAY: (f32, f32, f32) = (0.29, 0.33, 0.39);
+const ACCENT: (f32, f32, f32) = (0.12, 0.25, 0.69);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+#[derive(Clone, Copy)]
+struct TextStyle {
+    font: Font,
+    size: f32,
+    color: (f32, f32, f32),
+}
+
+impl TextStyle {
+    const fn new(font: Font, size: f32, color: (f32, f32, f32)) -> Self {
+        Self { font, size, color }
+    }
+
+    fn leading(&self) -> f32 {
+        self.size * 1.35
+    }
+
+    fn width(&self, text: &str) -> f32 {
+        let units: u32 = text
+            .chars()
+            .map(|c| u32::from(self.font.glyph_width(encode(c))))
+            .sum();
+        units as f32 * self.size / 1000.0
+    }
+}
+
+const NAME: TextStyle = TextStyle::new(Font::Bold, 22.0, BLACK);
+const HEADLINE: TextStyle = TextStyle::new(Font::Regular, 12.0, GRAY);
+const CONTACT: TextStyle = TextStyle::new(Font::Regular, 9.5, GRAY);
+const SECTION: TextStyle = TextStyle::new(Font::Bold, 12.0, ACCENT);
+const ITEM_TITLE: TextStyle = TextStyle::new(Font::Bold, 11.0, BLACK);
+const ITEM_META: TextStyle = TextStyle::new(Font::Regular, 10.0, GRAY);
+const BODY: TextStyle = TextStyle::new(Font::Regular, 10.0, BLACK);
+const DETAIL: TextStyle = TextStyle::new(Font::Regular, 9.0, GRAY);
+
+// Advance widths of the standard Helvetica faces for ASCII 32..=126, in 1/1000 em
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn glyph_width(self, byte: u8) -> u16 {
+        let bold = self == Font::Bold;
+        match byte {
+            32..=126 if bold => HELVETICA_BOLD_WIDTHS[usize::from(byte - 32)],
+            32..=126 => HELVETICA_WIDTHS[usize::from(byte - 32)],
+            0x91 | 0x92 => if bold { 278 } else { 222 },
+            0x93 | 0x94 => if bold { 500 } else { 333 },
+            0x85 | 0x97 => 1000,
+            0x95 => 350,
+            0xB7 => 278,
+            _ => 556,
+        }
+    }
+}
+
+/// Maps a character onto the WinAnsi encoding used by the standard PDF fonts.
+fn encode(c: char) -> u8 {
+    match c {
+        ' '..='~' => c as u8,
+        '\u{2018}' => 0x91,
+        '\u{2019}' => 0x92,
+        '\u{201C}' => 0x93,
+        '\u{201D}' => 0x94,
+        '\u{2022}' => 0x95,
+        '\u{2013}' => 0x96,
+        '\u{2014}' => 0x97,
+        '\u{2026}' => 0x85,
+        '\u{A0}'..='\u{FF}' => c as u8,
+        _ => b'?',
+    }
+}
+
+fn write_string(out: &mut Vec<u8>, text: &str) {
+    out.push(b'(');
+    for byte in text.chars().map(encode) {
+        if matches!(byte, b'(' | b')' | b'\\') {
+            out.push(b'\\');
+        }
+        out.push(byte);
+    }
+    out.push(b')');
+}
+
+fn write_text(page: &mut Vec<u8>, x: f32, baseline: f32, style: TextStyle, text: &str) {
+    let (r, g, b) = style.color;
+    page.extend_from_slice(
+        format!(
+            "BT {r:.2} {g:.2} {b:.2} rg /{} {:.1} Tf {x:.2} {baseline:.2} Td ",
+            style.font.resource(),
+            style.size
+        )
+        .as_bytes(),
+    );
+    write_string(page, text);
+    page.extend_from_slice(b" Tj ET\n");
+}
+
+/// Splits a word too wide for a line of its own, such as a long url, into pieces that each fit.
+fn break_word(style: TextStyle, width: f32, word: &str) -> Vec<String> {
+    if style.width(word) <= width {
+        return vec![word.to_string()];
+    }
+
+    let mut pieces = Vec::new();
+    let mut piece = String::new();
+    for c in word.chars() {
+        piece.push(c);
+        if style.width(&piece) > width && piece.chars().count() > 1 {
+            piece.pop();
+            pieces.push(std::mem::replace(&mut piece, c.to_string()));
+        }
+    }
+    pieces.push(piece);
+    pieces
+}
+
+fn wrap(style: TextStyle, width: f32, text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    let words = text
+        .split_whitespace()
+        .flat_map(|word| break_word(style, width, word));
+    for word in words {
+        let word = word.as_str();
+        if current.is_empty() {
+            current.push_str(word);
+            continue;
+        }
+
+        let candidate = format!("{current} {word}");
+        if style.width(&candidate) > width {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        } else {
+            current = candidate;
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+struct Layout {
+    pages: Vec<Vec<u8>>,
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn page(&mut self) -> &mut Vec<u8> {
+        self.pages.last_mut().expect("layout always has a page")
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN + FOOTER_HEIGHT {
+            self.pages.push(Vec::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn text_at(&mut self, x: f32, baseline: f32, style: TextStyle, text: &str) {
+        write_text(self.page(), x, baseline, style, text);
+    }
+
+    fn line(&mut self, style: TextStyle, text: &str) {
+        self.ensure_space(style.leading());
+        let baseline = self.y - style.size;
+        self.text_at(MARGIN, baseline, style, text);
+        self.y -= style.leading();
+    }
+
+    /// Writes `left` at the margin and `right` flush against the right margin on the same line.
+    fn split_line(&mut self, left: (TextStyle, &str), right: Option<(TextStyle, &str)>) {
+        let (left_style, left_text) = left;
+        let leading = right.map_or(left_style.leading(), |(style, _)| {
+            style.leading().max(left_style.leading())
+        });
+
+        self.ensure_space(leading);
+        let baseline = self.y - left_style.size;
+        self.text_at(MARGIN, baseline, left_style, left_text);
+        if let Some((right_style, right_text)) = right {
+            let x = PAGE_WIDTH - MARGIN - right_style.width(right_text);
+            self.text_at(x, baseline, right_style, right_text);
+        }
+        self.y -= leading;
+    }
+
+    fn paragraph(&mut self, style: TextStyle, text: &str) {
+        for line in wrap(style, CONTENT_WIDTH, text) {
+            self.line(style, &line);
+        }
+    }
+
+    fn rule(&mut self) {
+        let y = self.y - 2.0;
+        self.page().extend_from_slice(
+            format!(
+                "0.75 0.78 0.82 RG 0.6 w {MARGIN:.2} {y:.2} m {:.2} {y:.2} l S\n",
+                PAGE_WIDTH - MARGIN
+            )
+            .as_bytes(),
+        );
+        self.y -= 8.0;
+    }
+
+    fn section(&mut self, title: &str) {
+        // Keep a heading together with at least the first lines of its section
+        self.ensure_space(SECTION.leading() + ITEM_TITLE.leading() + BODY.leading() * 2.0 + 10.0);
+        self.gap(6.0);
+        self.line(SECTION, &title.to_uppercase());
+        self.rule();
+    }
+
+    fn footer(&mut self, name: &str) {
+        let total = self.pages.len();
+        let baseline = MARGIN - DETAIL.size;
+        for (index, page) in self.pages.iter_mut().enumerate() {
+            let page_label = format!("Page {} of {total}", index + 1);
+            let x = PAGE_WIDTH - MARGIN - DETAIL.width(&page_label);
+            write_text(page, MARGIN, baseline, DETAIL, name);
+            write_text(page, x, baseline, DETAIL, &page_label);
+        }
+    }
+}
+
+fn display_url(url: &str) -> &str {
+    let url = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("www.");
+    url.trim_end_matches('/')
+}
+
+fn render_header(layout: &mut Layout, profile: &Profile) {
+    layout.line(NAME, &profile.name);
+    layout.line(HEADLINE, &profile.title);
+
+    let links = profile.social_links();
+    let contact = [
+        profile.location.as_deref(),
+        profile.email.as_deref(),
+        profile.phone.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(links.iter().map(|link| display_url(&link.url)))
+    .collect::<Vec<_>>();
+
+    if !contact.is_empty() {
+        layout.gap(2.0);
+        layout.paragraph(CONTACT, &contact.join("  |  "));
+    }
+}
+
+fn render_experience(layout: &mut Layout, exp: &Experience) {
+    layout.ensure_space(ITEM_TITLE.leading() + ITEM_META.leading() + BODY.leading());
+    let dates = exp.dates.label();
+    layout.split_line(
+        (ITEM_TITLE, &exp.position),
+        dates.as_deref().map(|dates| (ITEM_META, dates)),
+    );
+    layout.line(ITEM_META, &exp.company);
+    if let Some(description) = &exp.description {
+        layout.paragraph(BODY, &markdown::plain_text(description));
+    }
+    if !exp.technologies.is_empty() {
+        layout.paragraph(
+            DETAIL,
+            &format!("Technologies: {}", exp.technologies.join(", ")),
+        );
+    }
+    layout.gap(8.0);
+}
+
+fn render_education(layout: &mut Layout, edu: &Education) {
+    layout.ensure_space(ITEM_TITLE.leading() + ITEM_META.leading());
+    let dates = edu.dates.label();
+    layout.split_line(
+        (ITEM_TITLE, &edu.degree),
+        dates.as_deref().map(|dates| (ITEM_META, dates)),
+    );
+    layout.line(ITEM_META, &edu.school);
+    if let Some(details) = &edu.details {
+        layout.paragraph(BODY, details);
+    }
+    layout.gap(8.0);
+}
+
+fn render_skill_category(layout: &mut Layout, category: &SkillCategory) {
+    layout.ensure_space(ITEM_TITLE.leading() + BODY.leading());
+    layout.line(TextStyle { size: 10.0, ..ITEM_TITLE }, &category.name);
+    layout.paragraph(BODY, &category.items.join(", "));
+    layout.gap(4.0);
+}
+
+fn write_document(title: &str, author: &str, pages: Vec<Vec<u8>>) -> Vec<u8> {
+    // Objects 1-5 are fixed, followed by a page and content stream pair per page
+    let first_page = 6;
+    let kids = (0..pages.len())
+        .map(|index| format!("{} 0 R", first_page + 2 * index))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut info = b"<< /Title ".to_vec();
+    write_string(&mut info, title);
+    info.extend_from_slice(b" /Author ");
+    write_string(&mut info, author);
+    info.extend_from_slice(b" /Producer (resume-portfolio) >>");
+
+    let mut objects = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+        info,
+    ];
+
+    for (index, content) in pages.into_iter().enumerate() {
+        let content_id = first_page + 2 * index + 1;
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {content_id} 0 R >>"
+            )
+            .into_bytes(),
+        );
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(&content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        out.extend_from_slice(object);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref = out.len();
+    out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+
+    out
+}
+
+/// Suggested download name, e.g. `Jane-Doe-Resume.pdf`.
+pub fn file_name(profile: &Profile) -> String {
+    let name = profile.name.split_whitespace().collect::<Vec<_>>().join("-");
+    format!("{name}-Resume.pdf")
+}
+
+/// Renders the resume as a paginated PDF using the standard Helvetica fonts, so no
+/// font data or external service is needed.
+pub fn render_resume(profile: &Profile, resume: &Resume) -> Vec<u8> {
+    let mut layout = Layout::new();
+
+    render_header(&mut layout, profile);
+
+    if !resume.experience.is_empty() {
+        layout.section("Experience");
+        for exp in &resume.experience {
+            render_experience(&mut layout, exp);
+        }
+    }
+
+    if !resume.education.is_empty() {
+        layout.section("Education");
+        for edu in &resume.education {
+            render_education(&mut layout, edu);
+        }
+    }
+
+    if !resume.skills.is_empty() {
+        layout.section("Skills");
+        for category in &resume.skills {
+            render_skill_category(&mut layout, category);
+        }
+    }
+
+    layout.footer(&profile.name);
+
+    write_document(
+        &format!("{} - Resume", profile.name),
+        &profile.name,
+        layout.pages,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{wrap, BODY, CONTENT_WIDTH};
+
+    #[test]
+    fn wraps_words_to_the_width() {
+        let text = "word ".repeat(200);
+        let lines = wrap(BODY, CONTENT_WIDTH, &text);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| BODY.width(line) <= CONTENT_WIDTH));
+        assert_eq!(lines.join(" "), text.trim_end());
+    }
+
+    #[test]
+    fn breaks_words_wider_than_a_line() {
+        let url = format!("https://example.com/{}", "a".repeat(300));
+        let lines = wrap(BODY, CONTENT_WIDTH, &format!("See {url} for more"));
+        assert!(lines.len() > 2);
+        assert!(lines.iter().all(|line| BODY.width(line) <= CONTENT_WIDTH));
+        assert_eq!(lines.concat().replace(' ', ""), format!("See{url}formore"));
+    }
+}
@@ -0,0 +1,114 @@
+//! The profile as a [vCard 4.0](https://www.rfc-editor.org/rfc/rfc6350) contact, for saving
+//! straight to an address book.
+
+use crate::data::Profile;
+
+pub const MIME_TYPE: &str = "text/vcard";
+
+// Longest line allowed before it has to be folded, in octets
+const LINE_LIMIT: usize = 75;
+
+pub fn file_name(profile: &Profile) -> String {
+    let name = profile
+        .name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    format!("{name}.vcf")
+}
+
+/// Escapes the characters that separate values in a property.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+/// Appends a content line, folding it onto continuation lines that start with a space.
+fn push_line(card: &mut String, line: &str) {
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > LINE_LIMIT {
+            card.push_str("\r\n ");
+            length = 1;
+        }
+        card.push(character);
+        length += character.len_utf8();
+    }
+    card.push_str("\r\n");
+}
+
+pub fn render(profile: &Profile) -> String {
+    let mut card = String::new();
+    let mut line = |line: String| push_line(&mut card, &line);
+
+    line("BEGIN:VCARD".to_string());
+    line("VERSION:4.0".to_string());
+    line(format!("FN:{}", escape(&profile.name)));
+    // The last word is taken as the family name, the rest as given names
+    let (given, family) = profile
+        .name
+        .trim()
+        .rsplit_once(' ')
+        .unwrap_or(("", profile.name.trim()));
+    line(format!("N:{};{};;;", escape(family), escape(given.trim())));
+    line(format!("TITLE:{}", escape(&profile.title)));
+    if let Some(email) = &profile.email {
+        line(format!("EMAIL;TYPE=work:{}", escape(email)));
+    }
+    if let Some(phone) = &profile.phone {
+        let number = phone
+            .chars()
+            .filter(|character| character.is_ascii_digit() || *character == '+')
+            .collect::<String>();
+        line(format!("TEL;VALUE=uri;TYPE=\"work,voice\":tel:{number}"));
+    }
+    if let (Some(location), Some((city, region))) = (&profile.location, profile.locality_region()) {
+        line(format!(
+            "ADR;TYPE=work;LABEL=\"{}\":;;;{};{};;",
+            location.replace('"', "'"),
+            escape(city),
+            escape(region.unwrap_or_default())
+        ));
+    }
+    for link in profile.social_links() {
+        line(format!("URL:{}", link.url));
+    }
+    if let Some(headline) = &profile.headline {
+        line(format!("NOTE:{}", escape(headline)));
+    }
+    line("END:VCARD".to_string());
+
+    card
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_value_separators() {
+        assert_eq!(escape("a,b;c\\d\ne"), r"a\,b\;c\\d\ne");
+    }
+
+    #[test]
+    fn folds_at_75_octets_between_characters() {
+        // `é` takes two octets and would end on the 76th
+        let line = format!("NOTE:{}é{}", "a".repeat(69), "ü".repeat(40));
+        let mut card = String::new();
+        push_line(&mut card, &line);
+
+        let lines = card
+            .strip_suffix("\r\n")
+            .unwrap()
+            .split("\r\n")
+            .collect::<Vec<_>>();
+        assert_eq!(lines[0], format!("NOTE:{}", "a".repeat(69)));
+        assert!(lines[1].starts_with(" é"));
+        assert!(lines.iter().all(|line| line.len() <= LINE_LIMIT));
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        assert_eq!(card.replace("\r\n ", ""), format!("{line}\r\n"));
+    }
+}
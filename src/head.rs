@@ -0,0 +1,320 @@
+use crate::content::Content;
+use crate::data::{slugify, Post};
+use crate::export::{feed, json_ld};
+use crate::{images, markdown, Route};
+use yew_router::Routable;
+
+const PROJECTS_IN_DESCRIPTION: usize = 3;
+const TAGS_IN_DESCRIPTION: usize = 3;
+
+/// Document title and the meta tags describing a page to search engines and link unfurlers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageMeta {
+    pub title: String,
+    pub description: String,
+    pub canonical: Option<String>,
+    pub site_name: String,
+    pub indexable: bool,
+    /// Absolute url of the image shown in link previews.
+    pub image: Option<String>,
+    /// Absolute urls of the feeds, for autodiscovery.
+    pub feeds: Vec<(feed::Format, String)>,
+    /// schema.org JSON-LD describing the person or projects on the page.
+    pub structured_data: Option<String>,
+}
+
+impl PageMeta {
+    pub fn for_route(route: &Route, content: &Content) -> Self {
+        let profile = &content.profile;
+        let name = profile.name.clone();
+
+        let (page, description) = match route {
+            Route::About => ("About Me".to_string(), content.about.intro.clone()),
+            Route::Resume => (
+                "Resume".to_string(),
+                format!(
+                    "Resume of {name}, {}: professional experience, education and skills.",
+                    profile.title
+                ),
+            ),
+            Route::Projects => {
+                let projects = &content.projects;
+                let names = projects
+                    .iter()
+                    .take(PROJECTS_IN_DESCRIPTION)
+                    .map(|project| project.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let mut description =
+                    format!("Projects and open source work by {name}, including {names}.");
+                if let Some(latest) = projects.first() {
+                    if let Some(summary) = &latest.description {
+                        let summary = markdown::plain_text(summary);
+                        description.push_str(&format!(" Latest: {} - {summary}", latest.name));
+                    }
+                }
+                ("Projects".to_string(), description)
+            }
+            Route::ProjectDetail { slug } => match content.project(slug) {
+                Some(project) => {
+                    let description = match &project.description {
+                        Some(description) => markdown::plain_text(description),
+                        None => {
+                            format!("{} by {name}: {}.", project.name, project.keywords.join(", "))
+                        }
+                    };
+                    (project.name.clone(), description)
+                }
+                None => return Self::for_route(&Route::NotFound, content),
+            },
+            Route::Blog => {
+                let tags = Post::tags(&content.posts)
+                    .into_iter()
+                    .take(TAGS_IN_DESCRIPTION)
+                    .map(|(tag, _)| tag)
+                    .collect::<Vec<_>>();
+                let mut description = format!("Blog posts by {name}");
+                if !tags.is_empty() {
+                    description.push_str(&format!(" on {}", tags.join(", ")));
+                }
+                description.push('.');
+                ("Blog".to_string(), description)
+            }
+            Route::Post { slug } => match content.post(slug) {
+                Some(post) => (post.title.clone(), post.summary.clone()),
+                None => return Self::for_route(&Route::NotFound, content),
+            },
+            Route::Tag { tag } => {
+                let name_of_tag = content
+                    .posts
+                    .iter()
+                    .flat_map(|post| &post.tags)
+                    .find(|name| slugify(name) == *tag);
+                match name_of_tag {
+                    Some(tag) => (
+                        format!("Posts tagged {tag}"),
+                        format!("Blog posts by {name} about {tag}."),
+                    ),
+                    None => return Self::for_route(&Route::NotFound, content),
+                }
+            }
+            Route::Contact => (
+                "Contact".to_string(),
+                format!("Get in touch with {name}, {}.", profile.title),
+            ),
+            #[cfg(feature = "legal-pages")]
+            Route::Terms => {
+                let terms = &content.terms;
+                let description = format!(
+                    "{} for {name}'s website, effective {}.",
+                    terms.title, terms.effective_date
+                );
+                (terms.title.clone(), description)
+            }
+            #[cfg(feature = "legal-pages")]
+            Route::Privacy => {
+                let privacy = &content.privacy;
+                let description = format!(
+                    "{} for {name}'s website, effective {}.",
+                    privacy.title, privacy.effective_date
+                );
+                (privacy.title.clone(), description)
+            }
+            Route::NotFound => (
+                "Page Not Found".to_string(),
+                "The page you're looking for doesn't exist or has been moved.".to_string(),
+            ),
+        };
+
+        let canonical = profile
+            .website
+            .as_deref()
+            .map(|website| format!("{}{}", website.trim_end_matches('/'), route.to_path()));
+
+        // A project's own image on its page, the avatar everywhere else. Previews can only load
+        // it from a full url, so a path without a website to resolve it against is left out
+        let image = match route {
+            Route::ProjectDetail { slug } => content
+                .project(slug)
+                .and_then(|project| project.image.as_deref()),
+            _ => None,
+        }
+        .or(profile.avatar.as_deref())
+        .map(|src| images::absolute_url(src, profile.website.as_deref()))
+        .filter(|url| !url.starts_with('/'));
+
+        let structured_data = match route {
+            Route::Projects => {
+                let items = content
+                    .projects
+                    .iter()
+                    .map(|project| json_ld::creative_work(profile, project))
+                    .collect();
+                Some(json_ld::to_script(&json_ld::Graph { items }))
+            }
+            Route::ProjectDetail { slug } => content
+                .project(slug)
+                .map(|project| json_ld::to_script(&json_ld::creative_work(profile, project))),
+            Route::NotFound => None,
+            _ => Some(json_ld::to_script(&json_ld::person(profile, &content.resume))),
+        };
+
+        Self {
+            title: format!("{name} - {page}"),
+            description,
+            canonical,
+            site_name: name,
+            indexable: *route != Route::NotFound,
+            image,
+            feeds: feed::urls(profile),
+            structured_data,
+        }
+    }
+
+    /// `(attribute, key, content)` of every `<meta>` tag for the page.
+    fn meta_tags(&self) -> Vec<(&'static str, &'static str, String)> {
+        let robots = if self.indexable { "index, follow" } else { "noindex" };
+
+        let mut tags = vec![
+            ("name", "description", self.description.clone()),
+            ("name", "robots", robots.to_string()),
+            ("property", "og:type", "website".to_string()),
+            ("property", "og:site_name", self.site_name.clone()),
+            ("property", "og:title", self.title.clone()),
+            ("property", "og:description", self.description.clone()),
+            ("name", "twitter:card", "summary".to_string()),
+            ("name", "twitter:title", self.title.clone()),
+            ("name", "twitter:description", self.description.clone()),
+        ];
+        if let Some(canonical) = &self.canonical {
+            tags.push(("property", "og:url", canonical.clone()));
+        }
+        if let Some(image) = &self.image {
+            tags.push(("property", "og:image", image.clone()));
+            tags.push(("name", "twitter:image", image.clone()));
+        }
+        tags
+    }
+
+    /// Markup for the document head, used when prerendering.
+    pub fn to_html(&self) -> String {
+        let mut html = format!("<title>{}</title>", escape(&self.title));
+        for (attribute, key, content) in self.meta_tags() {
+            html.push_str(&format!(
+                r#"<meta {attribute}="{key}" content="{}">"#,
+                escape(&content)
+            ));
+        }
+        if let Some(canonical) = &self.canonical {
+            html.push_str(&format!(r#"<link rel="canonical" href="{}">"#, escape(canonical)));
+        }
+        for (format, url) in &self.feeds {
+            html.push_str(&format!(
+                r#"<link rel="alternate" type="{}" title="{}" href="{}">"#,
+                format.mime_type(),
+                escape(&self.feed_title(*format)),
+                escape(url)
+            ));
+        }
+        if let Some(structured_data) = &self.structured_data {
+            html.push_str(&format!(
+                r#"<script type="{}">{structured_data}</script>"#,
+                json_ld::MIME_TYPE
+            ));
+        }
+        html
+    }
+
+    /// Updates the live document head, replacing whatever the previous page set.
+    pub fn apply(&self) {
+        let document = gloo::utils::document();
+        let Some(head) = document.head() else {
+            return;
+        };
+
+        document.set_title(&self.title);
+
+        let upsert = |selector: String, tag: &str, attributes: &[(&str, &str)]| {
+            let element = match document.query_selector(&selector).ok().flatten() {
+                Some(element) => element,
+                None => {
+                    let Ok(element) = document.create_element(tag) else {
+                        return;
+                    };
+                    let _ = head.append_child(&element);
+                    element
+                }
+            };
+            for (name, value) in attributes {
+                let _ = element.set_attribute(name, value);
+            }
+        };
+
+        let remove = |selector: &str| {
+            if let Some(element) = document.query_selector(selector).ok().flatten() {
+                element.remove();
+            }
+        };
+
+        for (attribute, key, content) in self.meta_tags() {
+            upsert(
+                format!(r#"meta[{attribute}="{key}"]"#),
+                "meta",
+                &[(attribute, key), ("content", &content)],
+            );
+        }
+
+        match &self.canonical {
+            Some(canonical) => upsert(
+                r#"link[rel="canonical"]"#.to_string(),
+                "link",
+                &[("rel", "canonical"), ("href", canonical)],
+            ),
+            None => {
+                remove(r#"link[rel="canonical"]"#);
+                remove(r#"meta[property="og:url"]"#);
+            }
+        }
+
+        if self.image.is_none() {
+            remove(r#"meta[property="og:image"]"#);
+            remove(r#"meta[name="twitter:image"]"#);
+        }
+
+        for (format, url) in &self.feeds {
+            upsert(
+                format!(r#"link[rel="alternate"][type="{}"]"#, format.mime_type()),
+                "link",
+                &[
+                    ("rel", "alternate"),
+                    ("type", format.mime_type()),
+                    ("title", &self.feed_title(*format)),
+                    ("href", url),
+                ],
+            );
+        }
+
+        let selector = format!(r#"script[type="{}"]"#, json_ld::MIME_TYPE);
+        match &self.structured_data {
+            Some(structured_data) => {
+                upsert(selector.clone(), "script", &[("type", json_ld::MIME_TYPE)]);
+                if let Some(script) = document.query_selector(&selector).ok().flatten() {
+                    script.set_text_content(Some(structured_data));
+                }
+            }
+            None => remove(&selector),
+        }
+    }
+
+    fn feed_title(&self, format: feed::Format) -> String {
+        format!("{} ({})", self.site_name, format.label())
+    }
+}
+
+pub(crate) fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
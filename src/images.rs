@@ -0,0 +1,87 @@
+/// Where the images live in the repository. Trunk copies them to [`PUBLIC_PATH`] unchanged.
+pub const SOURCE_DIR: &str = "assets/images";
+pub const PUBLIC_PATH: &str = "/images";
+
+/// Widths of the resized variants, skipping any wider than the original. The smallest serve
+/// avatars and thumbnails on high density screens.
+pub const VARIANT_WIDTHS: [u32; 5] = [96, 192, 480, 960, 1440];
+
+/// Extension and mime type of each variant format, in order of preference.
+pub const VARIANT_FORMATS: [(&str, &str); 2] = [("avif", "image/avif"), ("webp", "image/webp")];
+
+/// An image found under [`SOURCE_DIR`] at build time.
+#[derive(Debug, PartialEq)]
+pub struct ImageMeta {
+    /// Path relative to [`SOURCE_DIR`], which is how the datafiles refer to it.
+    pub path: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Tiny png data url shown blurred while the image loads.
+    pub placeholder: &'static str,
+}
+
+const IMAGES: &[ImageMeta] = include!(concat!(env!("OUT_DIR"), "/images.rs"));
+
+pub fn all() -> &'static [ImageMeta] {
+    IMAGES
+}
+
+pub fn find(path: &str) -> Option<&'static ImageMeta> {
+    let path = path.trim_start_matches('/');
+    IMAGES.iter().find(|image| image.path == path)
+}
+
+/// Public url of an image referenced from a datafile, leaving full urls untouched.
+pub fn public_url(src: &str) -> String {
+    if src.contains("://") || src.starts_with('/') {
+        src.to_string()
+    } else {
+        format!("{PUBLIC_PATH}/{src}")
+    }
+}
+
+/// Full url of an image referenced from a datafile, for consumers outside the site such as feed
+/// readers and search engines. Paths on this site only resolve against its `website`.
+pub fn absolute_url(src: &str, website: Option<&str>) -> String {
+    let url = public_url(src);
+    match website {
+        Some(website) if url.starts_with('/') => format!("{}{url}", website.trim_end_matches('/')),
+        _ => url,
+    }
+}
+
+impl ImageMeta {
+    pub fn url(&self) -> String {
+        format!("{PUBLIC_PATH}/{}", self.path)
+    }
+
+    pub fn widths(&self) -> Vec<u32> {
+        VARIANT_WIDTHS
+            .into_iter()
+            .filter(|width| *width < self.width)
+            .chain([self.width])
+            .collect()
+    }
+
+    /// Path of a resized variant relative to [`PUBLIC_PATH`], e.g. `projects/app-480.webp`.
+    pub fn variant_path(&self, width: u32, extension: &str) -> String {
+        let stem = self
+            .path
+            .rsplit_once('.')
+            .map_or(self.path, |(stem, _)| stem);
+        format!("{stem}-{width}.{extension}")
+    }
+
+    pub fn srcset(&self, extension: &str) -> String {
+        self.widths()
+            .into_iter()
+            .map(|width| {
+                format!(
+                    "{PUBLIC_PATH}/{} {width}w",
+                    self.variant_path(width, extension)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
@@ -1,13 +1,30 @@
+use stylist::manager::StyleManager;
+use stylist::yew::ManagerProvider;
 use yew::prelude::*;
+use yew_router::history::{AnyHistory, BrowserHistory};
 use yew_router::prelude::*;
 
 mod components;
-mod data;
+pub mod config;
+mod content;
+pub mod data;
+pub mod export;
+mod head;
+pub mod images;
+mod markdown;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod prerender;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sitemap;
+mod theme;
 
-use components::{AboutView, ContactView, NotFoundView, PrivacyView, ProjectsView, ResumeView, Sidebar, TermsView};
-use data::Profile;
-
-pub const BASE_URL: &str = "{API_SERVER}";
+use components::{AboutView, BlogView, ConfigErrorView, ContactView, ContentStatusBar, NotFoundView, PostView, ProjectDetailView, ProjectsView, ResumeView, Sidebar};
+#[cfg(feature = "legal-pages")]
+use components::{PrivacyView, TermsView};
+use config::Config;
+use content::{use_content, Content};
+use head::PageMeta;
+use theme::{use_apply_theme, ThemeMode, ThemeStyles};
 
 #[derive(Clone, Routable, PartialEq)]
 enum Route {
@@ -17,10 +34,20 @@ enum Route {
     Resume,
     #[at("/projects")]
     Projects,
+    #[at("/projects/:slug")]
+    ProjectDetail { slug: String },
+    #[at("/blog")]
+    Blog,
+    #[at("/blog/:slug")]
+    Post { slug: String },
+    #[at("/blog/tags/:tag")]
+    Tag { tag: String },
     #[at("/contact")]
     Contact,
+    #[cfg(feature = "legal-pages")]
     #[at("/terms")]
     Terms,
+    #[cfg(feature = "legal-pages")]
     #[at("/privacy")]
     Privacy,
     #[not_found]
@@ -28,25 +55,125 @@ enum Route {
     NotFound,
 }
 
+impl Route {
+    /// Terms and Privacy, which stay reachable by url when `show_legal` hides them but are kept out
+    /// of the prerendered pages, the sitemap and search results.
+    pub fn is_legal(&self) -> bool {
+        match self {
+            #[cfg(feature = "legal-pages")]
+            Route::Terms | Route::Privacy => true,
+            _ => false,
+        }
+    }
+}
+
 fn switch(routes: Route) -> Html {
     match routes {
         Route::About => html! { <AboutView /> },
         Route::Resume => html! { <ResumeView /> },
         Route::Projects => html! { <ProjectsView /> },
+        Route::ProjectDetail { slug } => html! { <ProjectDetailView {slug} /> },
+        Route::Blog => html! { <BlogView /> },
+        Route::Post { slug } => html! { <PostView {slug} /> },
+        Route::Tag { tag } => html! { <BlogView {tag} /> },
         Route::Contact => html! { <ContactView /> },
+        #[cfg(feature = "legal-pages")]
         Route::Terms => html! { <TermsView /> },
+        #[cfg(feature = "legal-pages")]
         Route::Privacy => html! { <PrivacyView /> },
         Route::NotFound => html! { <NotFoundView /> },
     }
 }
 
+#[derive(Properties, PartialEq)]
+pub struct AppProps {
+    pub history: AnyHistory,
+    pub manager: StyleManager,
+}
+
+// The browser and the prerenderer must render the exact same component tree for hydration to
+// succeed, so they share this root and only differ in the history and style manager they pass in.
 #[function_component(App)]
-fn app() -> Html {
+pub fn app(props: &AppProps) -> Html {
+    // Start from the build configuration and the embedded content so prerendered markup matches
+    // the first client render, then apply any runtime overrides once they arrive, drop the form
+    // if its API is down and swap in the remote content when there is a source for it. Only a
+    // configuration still invalid after the overrides replaces the site with the error page
+    let config = use_state(|| Ok(Config::initial()));
+    let content = use_state(Content::embedded);
+    let theme = use_state(ThemeMode::stored);
+    use_apply_theme(*theme);
+
+    {
+        let config = config.clone();
+        let content = content.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let resolved = match config::fetch_overrides().await {
+                    Ok(Some(overrides)) => Config::from_build().merge(overrides).validate(),
+                    Ok(None) => Config::from_build().validate(),
+                    Err(err) => Err(err),
+                };
+                let resolved = match resolved {
+                    Ok(resolved) => Ok(resolved.check_api().await),
+                    Err(err) => Err(err),
+                };
+                let content_url = resolved
+                    .as_ref()
+                    .ok()
+                    .and_then(|resolved| resolved.content_url.clone());
+                if resolved != *config {
+                    config.set(resolved);
+                }
+
+                if let Some(url) = content_url {
+                    let cached = Content::cached(&url);
+                    content.set(cached.clone());
+                    content.set(cached.fetch(&url).await);
+                }
+            });
+        });
+    }
+
+    html! {
+        <ManagerProvider manager={props.manager.clone()}>
+            <ThemeStyles />
+            <Router history={props.history.clone()}>
+                {match &*config {
+                    Ok(config) => html! {
+                        <ContextProvider<Config> context={config.clone()}>
+                            <ContextProvider<Content> context={(*content).clone()}>
+                                <ContextProvider<UseStateHandle<ThemeMode>> context={theme.clone()}>
+                                    <Layout />
+                                </ContextProvider<UseStateHandle<ThemeMode>>>
+                            </ContextProvider<Content>>
+                        </ContextProvider<Config>>
+                    },
+                    Err(err) => html! { <ConfigErrorView message={err.to_string()} /> },
+                }}
+            </Router>
+        </ManagerProvider>
+    }
+}
+
+#[function_component(Layout)]
+fn layout() -> Html {
     let mobile_menu_open = use_state(|| false);
     let current_route = use_route::<Route>().unwrap_or(Route::About);
+    let content = use_content();
 
-    let profile_str = include_str!("datafile/profile.json");
-    let profile: Profile = serde_json::from_str(profile_str).expect("valid json");
+    let show_legal = use_context::<Config>()
+        .expect("Config context is missing")
+        .show_legal;
+
+    use_effect_with(
+        (current_route.clone(), content, show_legal),
+        |(route, content, show_legal)| {
+            let mut meta = PageMeta::for_route(route, content);
+            meta.indexable &= *show_legal || !route.is_legal();
+            meta.apply();
+        },
+    );
 
     let toggle_mobile_menu = {
         let mobile_menu_open = mobile_menu_open.clone();
@@ -70,51 +197,75 @@ fn app() -> Html {
     };
 
     html! {
-        <BrowserRouter>
-            <div class="container">
-                // Mobile menu button
-                <button
-                    class="mobile-menu-btn"
-                    onclick={toggle_mobile_menu}
-                >
-                    <i class={if *mobile_menu_open { "fas fa-times" } else { "fas fa-bars" }}></i>
-                </button>
-
-                // Mobile overlay
-                if *mobile_menu_open {
-                    <div class="mobile-overlay" onclick={close_mobile_menu.clone()}></div>
-                }
+        <div class="container">
+            <ContentStatusBar />
+
+            // Mobile menu button
+            <button
+                class="mobile-menu-btn"
+                onclick={toggle_mobile_menu}
+            >
+                <i class={if *mobile_menu_open { "fas fa-times" } else { "fas fa-bars" }}></i>
+            </button>
+
+            // Mobile overlay
+            if *mobile_menu_open {
+                <div class="mobile-overlay" onclick={close_mobile_menu.clone()}></div>
+            }
+
+            // Desktop sidebar
+            <Sidebar
+                current_route={current_route.clone()}
+                is_mobile={false}
+                is_open={true}
+                on_navigate={on_navigate.clone()}
+            />
 
-                // Desktop sidebar
+            // Mobile sidebar
+            if *mobile_menu_open {
                 <Sidebar
-                    profile={profile.clone()}
                     current_route={current_route.clone()}
-                    is_mobile={false}
-                    is_open={true}
-                    on_navigate={on_navigate.clone()}
+                    is_mobile={true}
+                    is_open={*mobile_menu_open}
+                    on_navigate={on_navigate}
                 />
+            }
 
-                // Mobile sidebar
-                if *mobile_menu_open {
-                    <Sidebar
-                        profile={profile.clone()}
-                        current_route={current_route.clone()}
-                        is_mobile={true}
-                        is_open={*mobile_menu_open}
-                        on_navigate={on_navigate}
-                    />
-                }
-
-                // Main content
-                <main class={if *mobile_menu_open { "main-content main-content-mobile" } else { "main-content" }}>
-                    <Switch<Route> render={switch} />
-                </main>
-            </div>
-        </BrowserRouter>
+            // Main content
+            <main class={if *mobile_menu_open { "main-content main-content-mobile" } else { "main-content" }}>
+                <Switch<Route> render={switch} />
+            </main>
+        </div>
     }
 }
 
 #[wasm_bindgen::prelude::wasm_bindgen(start)]
 pub fn run_app() {
-    yew::Renderer::<App>::new().render();
+    let root = gloo::utils::document()
+        .get_element_by_id("app")
+        .expect("Missing #app element");
+    let props = AppProps {
+        history: BrowserHistory::new().into(),
+        manager: StyleManager::new().expect("Failed to create style manager"),
+    };
+
+    // Pages produced by the prerenderer already contain the markup for their route, so attach to
+    // it instead of rendering from scratch. A host falling back to another page's html for an
+    // unknown path would fail hydration, so that markup is discarded instead. The same goes for
+    // query strings, which views like the project filters render differently.
+    let location = gloo::utils::window().location();
+    let pathname = location.pathname().unwrap_or_default();
+    let search = location.search().unwrap_or_default();
+    let prerendered = search.is_empty()
+        && root
+            .get_attribute("data-prerendered")
+            .is_some_and(|path| path.trim_end_matches('/') == pathname.trim_end_matches('/'));
+
+    let renderer = yew::Renderer::<App>::with_root_and_props(root.clone(), props);
+    if prerendered {
+        renderer.hydrate();
+    } else {
+        root.set_inner_html("");
+        renderer.render();
+    }
 }
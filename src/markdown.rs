@@ -0,0 +1,134 @@
+use pulldown_cmark::{html, Event, Options, Parser, TagEnd};
+use yew::{AttrValue, Html};
+
+mod sanitize;
+
+/// Colors for the code blocks highlighted in posts, generated by `build.rs` from its syntax themes.
+pub const HIGHLIGHT_CSS: &str = include_str!(concat!(env!("OUT_DIR"), "/highlight.css"));
+
+/// Markdown converted to html that is safe to put in the page. Content can be loaded from
+/// elsewhere at startup, so it goes through [`sanitize::sanitize`] like untrusted input.
+fn to_html(source: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let events = sanitize::sanitize(Parser::new_ext(source, options));
+
+    let mut output = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut output, events);
+    output
+}
+
+/// Renders markdown from the datafiles into html, see [`sanitize::sanitize`] for what is left out.
+pub fn render(source: &str) -> Html {
+    Html::from_html_unchecked(AttrValue::from(to_html(source)))
+}
+
+/// The text of markdown without its formatting, for places that only take plain text such as
+/// meta descriptions, feeds and the PDF.
+pub fn plain_text(source: &str) -> String {
+    let mut text = String::with_capacity(source.len());
+    for event in Parser::new(source) {
+        match event {
+            Event::Text(value) | Event::Code(value) => text.push_str(&value),
+            Event::SoftBreak
+            | Event::HardBreak
+            | Event::End(TagEnd::Paragraph | TagEnd::Item | TagEnd::Heading(_)) => text.push(' '),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Html that `build.rs` already rendered from markdown, such as a post's body.
+pub fn rendered(html: &str) -> Html {
+    Html::from_html_unchecked(AttrValue::from(html.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_html;
+
+    #[test]
+    fn drops_script_links() {
+        for source in [
+            "[click](javascript:alert(1))",
+            "[click](JavaScript:alert(1))",
+            "[click](data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==)",
+            "[click](vbscript:msgbox(1))",
+            "<javascript:alert(1)>",
+        ] {
+            let html = to_html(source);
+            assert!(!html.contains("<a"), "{source} rendered as {html}");
+            assert!(!html.contains("href"), "{source} rendered as {html}");
+        }
+        assert_eq!(to_html("[click](javascript:alert(1))"), "<p>click</p>\n");
+    }
+
+    #[test]
+    fn drops_entity_encoded_schemes() {
+        for source in [
+            "[click](jav&#x61;script:alert(1))",
+            "[click](&#106;avascript:alert(1))",
+            "[click](javascript&colon;alert(1))",
+            "[click](java&#9;script:alert(1))",
+            "[click](<java\tscript:alert(1)>)",
+            "[click](&#x20;javascript:alert(1))",
+        ] {
+            let html = to_html(source);
+            assert!(!html.contains("<a"), "{source} rendered as {html}");
+        }
+    }
+
+    #[test]
+    fn drops_script_images() {
+        for source in [
+            "![x](javascript:alert(1))",
+            "![x](data:image/svg+xml;base64,PHN2Zz48L3N2Zz4=)",
+            "![x](jav&#x61;script:alert(1))",
+        ] {
+            let html = to_html(source);
+            assert!(
+                html.contains(r#"<img src="""#),
+                "{source} rendered as {html}"
+            );
+        }
+    }
+
+    #[test]
+    fn shows_raw_html_as_text() {
+        let inline = to_html("Hello <script>alert(1)</script> there");
+        assert!(!inline.contains("<script"), "{inline}");
+        assert!(
+            inline.contains("&lt;script&gt;alert(1)&lt;/script&gt;"),
+            "{inline}"
+        );
+
+        let block = to_html("<script>alert(1)</script>\n\nAfter");
+        assert!(!block.contains("<script"), "{block}");
+
+        let attribute = to_html(r#"<img src="x" onerror="alert(1)">"#);
+        assert!(!attribute.contains("<img"), "{attribute}");
+    }
+
+    #[test]
+    fn keeps_safe_links() {
+        assert_eq!(
+            to_html("[about](/about) and [mail](mailto:me@example.com)"),
+            "<p><a href=\"/about\">about</a> and <a href=\"mailto:me@example.com\">mail</a></p>\n"
+        );
+        assert_eq!(
+            to_html("[time](/notes?at=10:30)"),
+            "<p><a href=\"/notes?at=10:30\">time</a></p>\n"
+        );
+        assert!(to_html("![x](/images/x.png)").contains(r#"<img src="/images/x.png""#));
+    }
+
+    #[test]
+    fn opens_external_links_in_a_new_tab() {
+        assert_eq!(
+            to_html(r#"[site](https://example.com "Example")"#),
+            "<p><a href=\"https://example.com\" title=\"Example\" target=\"_blank\" \
+             rel=\"noopener noreferrer\">site</a></p>\n"
+        );
+        assert!(!to_html("[about](/about)").contains("target"));
+    }
+}
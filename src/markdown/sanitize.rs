@@ -0,0 +1,71 @@
+//! What is left out of markdown before it reaches the page, shared with `build.rs` so posts
+//! rendered at build time follow the same rules as content loaded at runtime.
+
+use pulldown_cmark::{html, CowStr, Event, Tag, TagEnd};
+
+/// Whether markdown may link to `url`. Only web, mail and phone links are allowed besides
+/// relative ones, so nothing like `javascript:` makes it into the page.
+pub fn is_safe_url(url: &str) -> bool {
+    match url.split_once(':') {
+        // A colon after a `/`, `?` or `#` belongs to the path, query or fragment of a relative url
+        Some((scheme, _)) if !scheme.contains(['/', '?', '#']) => matches!(
+            scheme.to_ascii_lowercase().as_str(),
+            "http" | "https" | "mailto" | "tel"
+        ),
+        _ => true,
+    }
+}
+
+fn is_external(url: &str) -> bool {
+    ["http://", "https://", "//"]
+        .iter()
+        .any(|prefix| url.starts_with(prefix))
+}
+
+/// The opening tag of `link` as html, opening external links in a new tab.
+fn link_open(link: Tag) -> String {
+    let external = matches!(&link, Tag::Link { dest_url, .. } if is_external(dest_url));
+    let mut html = String::new();
+    html::push_html(&mut html, std::iter::once(Event::Start(link)));
+    if external {
+        html.pop();
+        html.push_str(r#" target="_blank" rel="noopener noreferrer">"#);
+    }
+    html
+}
+
+/// Treats the markdown as untrusted: raw html is shown as the text it was written as, links and
+/// images to unsafe urls are dropped, and external links open in a new tab without access to this
+/// one.
+pub fn sanitize<'a>(events: impl Iterator<Item = Event<'a>>) -> impl Iterator<Item = Event<'a>> {
+    // Whether each link still open was kept, so its end is dropped along with it
+    let mut links = Vec::new();
+    events.filter_map(move |event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Some(Event::Text(html)),
+        Event::Start(link @ Tag::Link { .. }) => {
+            let safe = matches!(&link, Tag::Link { dest_url, .. } if is_safe_url(dest_url));
+            links.push(safe);
+            safe.then(|| Event::InlineHtml(link_open(link).into()))
+        }
+        Event::End(TagEnd::Link) => links
+            .pop()
+            .unwrap_or(false)
+            .then_some(Event::InlineHtml(CowStr::Borrowed("</a>"))),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Some(Event::Start(Tag::Image {
+            link_type,
+            dest_url: if is_safe_url(&dest_url) {
+                dest_url
+            } else {
+                CowStr::Borrowed("")
+            },
+            title,
+            id,
+        })),
+        event => Some(event),
+    })
+}
@@ -0,0 +1,113 @@
+use crate::config::Config;
+use crate::content::Content;
+use crate::data::{get_posts, get_projects, slugify, Post};
+use crate::head::PageMeta;
+use crate::{App, AppProps, Route};
+use stylist::manager::{render_static, StyleManager};
+use yew::ServerRenderer;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::Routable;
+
+pub struct RenderedPage {
+    pub path: String,
+    pub head: String,
+    pub body: String,
+}
+
+impl RenderedPage {
+    /// Location of the page relative to the dist directory, e.g. `resume/index.html`.
+    pub fn file_path(&self) -> String {
+        match self.path.trim_matches('/') {
+            "" => "index.html".to_string(),
+            path => format!("{path}/index.html"),
+        }
+    }
+
+    /// Places the rendered markup into the html shell produced by trunk, replacing its `<title>`.
+    ///
+    /// Returns `None` when the shell has no empty `#app` mount point after its `</head>`.
+    pub fn inject(&self, template: &str) -> Option<String> {
+        // Trunk may minify the shell, which drops the quotes around attribute values
+        let mount = template
+            .find(r#"id="app""#)
+            .or_else(|| template.find("id=app"))?;
+        let open_end = mount + template[mount..].find('>')? + 1;
+        let close = open_end + template[open_end..].find("</div>")?;
+        let head_end = template.find("</head>")?;
+        if head_end > mount || !template[open_end..close].trim().is_empty() {
+            return None;
+        }
+
+        let mut html = String::with_capacity(template.len() + self.head.len() + self.body.len());
+        let head = &template[..head_end];
+        match (head.find("<title>"), head.find("</title>")) {
+            (Some(start), Some(end)) if start < end => {
+                html.push_str(&head[..start]);
+                html.push_str(&head[end + "</title>".len()..]);
+            }
+            _ => html.push_str(head),
+        }
+        html.push_str(&self.head);
+        html.push_str(&template[head_end..open_end - 1]);
+        html.push_str(&format!(r#" data-prerendered="{}">"#, self.path));
+        html.push_str(&self.body);
+        html.push_str(&template[close..]);
+        Some(html)
+    }
+}
+
+/// Every route without route parameters, followed by a page for each project, post and tag. The
+/// legal pages are left out when the build configuration hides them.
+pub(crate) fn routes() -> Vec<Route> {
+    let show_legal = Config::initial().show_legal;
+    let projects = get_projects()
+        .iter()
+        .map(|project| Route::ProjectDetail { slug: project.slug() });
+    let posts = get_posts().iter().map(|post| Route::Post {
+        slug: post.slug.clone(),
+    });
+    let tags = Post::tags(get_posts()).into_iter().map(|(tag, _)| Route::Tag {
+        tag: slugify(tag),
+    });
+
+    Route::routes()
+        .into_iter()
+        .filter(|pattern| !pattern.contains([':', '*']))
+        .filter_map(Route::recognize)
+        .filter(|route| show_legal || !route.is_legal())
+        .chain(projects)
+        .chain(posts)
+        .chain(tags)
+        .collect()
+}
+
+/// Paths of the pages to prerender, see [`routes`].
+pub fn paths() -> Vec<String> {
+    routes().iter().map(Route::to_path).collect()
+}
+
+/// Renders `path` through the server renderer, collecting the styles it mounts so they can be
+/// inlined into the document head.
+pub async fn render(path: String) -> RenderedPage {
+    let (writer, reader) = render_static();
+
+    let history_path = path.clone();
+    let body = ServerRenderer::<App>::with_props(move || AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries([history_path])),
+        manager: StyleManager::builder()
+            .writer(writer)
+            .build()
+            .expect("Failed to create style manager"),
+    })
+    .render()
+    .await;
+
+    let route = Route::recognize(&path).unwrap_or(Route::NotFound);
+    let mut head = PageMeta::for_route(&route, &Content::embedded()).to_html();
+    reader
+        .read_style_data()
+        .write_static_markup(&mut head)
+        .expect("Failed to write styles");
+
+    RenderedPage { path, head, body }
+}
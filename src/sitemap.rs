@@ -0,0 +1,99 @@
+//! The [sitemap](https://www.sitemaps.org/protocol.html) and `robots.txt` telling search engines
+//! which pages exist and which to leave alone.
+
+use crate::data::{get_posts, get_project, get_projects, get_resume, Date, DateEnd, Dates, Post};
+use crate::head::escape;
+use crate::{prerender, Route};
+use yew_router::Routable;
+
+pub const SITEMAP_FILE_NAME: &str = "sitemap.xml";
+pub const ROBOTS_FILE_NAME: &str = "robots.txt";
+
+/// The last date the dates are known to cover; ongoing ranges say nothing about when they changed.
+fn last_known(dates: &Dates) -> Option<Date> {
+    match dates.end {
+        Some(DateEnd::On(end)) => Some(end.first_day()),
+        _ => dates.start.map(|start| start.first_day()),
+    }
+}
+
+fn newest_post<'a>(posts: impl Iterator<Item = &'a Post>) -> Option<Date> {
+    posts.map(|post| post.date).max()
+}
+
+/// When the content behind the page last changed, as far as the datafiles tell.
+fn last_modified(route: &Route) -> Option<Date> {
+    match route {
+        Route::Resume => {
+            let resume = get_resume();
+            let experience = resume.experience.iter().map(|entry| &entry.dates);
+            let education = resume.education.iter().map(|entry| &entry.dates);
+            experience.chain(education).filter_map(last_known).max()
+        }
+        Route::Projects => get_projects()
+            .iter()
+            .filter_map(|project| last_known(&project.dates))
+            .max(),
+        Route::ProjectDetail { slug } => {
+            get_project(slug).and_then(|project| last_known(&project.dates))
+        }
+        Route::Blog => newest_post(get_posts().iter()),
+        Route::Post { slug } => newest_post(get_posts().iter().filter(|post| post.slug == *slug)),
+        Route::Tag { tag } => newest_post(get_posts().iter().filter(|post| post.has_tag(tag))),
+        #[cfg(feature = "legal-pages")]
+        Route::Terms => Date::parse_written(&crate::data::get_terms().effective_date),
+        #[cfg(feature = "legal-pages")]
+        Route::Privacy => Date::parse_written(&crate::data::get_privacy().effective_date),
+        Route::About | Route::Contact | Route::NotFound => None,
+    }
+}
+
+/// Every prerendered page worth finding through a search engine, leaving out the not found page
+/// and the blog until it has posts.
+fn routes() -> Vec<Route> {
+    prerender::routes()
+        .into_iter()
+        .filter(|route| match route {
+            Route::NotFound => false,
+            Route::Blog => !get_posts().is_empty(),
+            _ => true,
+        })
+        .collect()
+}
+
+/// `sitemap.xml` listing every page under `website`.
+pub fn render(website: &str) -> String {
+    let website = website.trim_end_matches('/');
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    xml.push_str(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#);
+    for route in routes() {
+        let url = format!("{website}{}", route.to_path());
+        xml.push_str(&format!("<url><loc>{}</loc>", escape(&url)));
+        if let Some(date) = last_modified(&route) {
+            xml.push_str(&format!("<lastmod>{date}</lastmod>"));
+        }
+        xml.push_str("</url>");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// `robots.txt` keeping crawlers out of the `disallow` paths and pointing them to the sitemap,
+/// when there is a `website` to publish it under.
+pub fn robots(website: Option<&str>, disallow: &[String]) -> String {
+    let mut robots = String::from("User-agent: *\n");
+    if disallow.is_empty() {
+        // An empty rule allows everything
+        robots.push_str("Disallow:\n");
+    }
+    for path in disallow {
+        robots.push_str(&format!("Disallow: {path}\n"));
+    }
+    if let Some(website) = website {
+        robots.push_str(&format!(
+            "\nSitemap: {}/{SITEMAP_FILE_NAME}\n",
+            website.trim_end_matches('/')
+        ));
+    }
+    robots
+}
@@ -0,0 +1,159 @@
+use crate::data::{get_theme, hex_rgb, Palette, Theme};
+use gloo::events::EventListener;
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use stylist::yew::Global;
+use yew::prelude::*;
+
+/// Key the chosen mode is stored under, also read by the script in `index.html`.
+const STORAGE_KEY: &str = "theme";
+const PREFERS_LIGHT: &str = "(prefers-color-scheme: light)";
+
+/// The theme the visitor picked, applied as `data-theme` on the root element so the custom
+/// properties switch to the light colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    /// Follow `prefers-color-scheme`, including when it changes.
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+    /// The mode saved by an earlier visit. Prerendered pages always start from the default, the
+    /// script in `index.html` has applied the saved one by the time they are shown.
+    pub fn stored() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::default()
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "Light",
+            ThemeMode::Dark => "Dark",
+            ThemeMode::System => "System",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "fas fa-sun",
+            ThemeMode::Dark => "fas fa-moon",
+            ThemeMode::System => "fas fa-desktop",
+        }
+    }
+
+    fn is_light(&self) -> bool {
+        match self {
+            ThemeMode::Light => true,
+            ThemeMode::Dark => false,
+            ThemeMode::System => gloo::utils::window()
+                .match_media(PREFERS_LIGHT)
+                .ok()
+                .flatten()
+                .is_some_and(|query| query.matches()),
+        }
+    }
+
+    fn apply(&self) {
+        let theme = if self.is_light() { "light" } else { "dark" };
+        if let Some(root) = gloo::utils::document().document_element() {
+            let _ = root.set_attribute("data-theme", theme);
+        }
+    }
+
+    fn save(&self) {
+        match self {
+            ThemeMode::System => LocalStorage::delete(STORAGE_KEY),
+            // Running out of storage only means the choice is forgotten
+            mode => {
+                let _ = LocalStorage::set(STORAGE_KEY, mode);
+            }
+        }
+    }
+}
+
+/// Applies and saves `mode` whenever it changes, following the system while it is
+/// [`ThemeMode::System`].
+#[hook]
+pub fn use_apply_theme(mode: ThemeMode) {
+    use_effect_with(mode, |mode| {
+        mode.apply();
+        mode.save();
+
+        let listener = (*mode == ThemeMode::System)
+            .then(|| gloo::utils::window().match_media(PREFERS_LIGHT).ok().flatten())
+            .flatten()
+            .map(|query| EventListener::new(&query, "change", |_| ThemeMode::System.apply()));
+        move || drop(listener)
+    });
+}
+
+#[hook]
+pub fn use_theme() -> UseStateHandle<ThemeMode> {
+    use_context::<UseStateHandle<ThemeMode>>().expect("Theme context is missing")
+}
+
+/// Media query for the viewports that get the single column layout, as `@media ${mobile}`.
+pub fn mobile() -> String {
+    format!("(max-width: {}px)", get_theme().breakpoints.mobile)
+}
+
+/// The tokens as `--color-*`, `--space-*` and `--radius-*` custom properties. Each color also
+/// gets a `-rgb` variant for use with an alpha, as in `rgba(var(--color-text-rgb), 0.5)`.
+fn custom_properties(theme: &Theme) -> String {
+    fn palette(css: &mut String, palette: &Palette) {
+        writeln!(css, "--color-background: {};", palette.background).unwrap();
+        for (name, value) in palette.colors() {
+            writeln!(css, "--color-{name}: {value};").unwrap();
+            if let Some([red, green, blue]) = hex_rgb(value) {
+                writeln!(css, "--color-{name}-rgb: {red}, {green}, {blue};").unwrap();
+            }
+        }
+    }
+
+    let mut css = String::from(":root {\ncolor-scheme: dark;\n");
+    palette(&mut css, &theme.dark);
+    let spacing = &theme.spacing;
+    for (name, value) in [
+        ("xs", &spacing.xs),
+        ("sm", &spacing.sm),
+        ("md", &spacing.md),
+        ("lg", &spacing.lg),
+        ("xl", &spacing.xl),
+    ] {
+        writeln!(css, "--space-{name}: {value};").unwrap();
+    }
+    let radii = &theme.radii;
+    for (name, value) in [
+        ("sm", &radii.sm),
+        ("md", &radii.md),
+        ("lg", &radii.lg),
+        ("pill", &radii.pill),
+    ] {
+        writeln!(css, "--radius-{name}: {value};").unwrap();
+    }
+    css.push_str("}\n:root[data-theme=\"light\"] {\ncolor-scheme: light;\n");
+    palette(&mut css, &theme.light);
+    css.push('}');
+    css
+}
+
+/// Defines the custom properties from `theme.json` that every style refers to.
+#[function_component(ThemeStyles)]
+pub fn theme_styles() -> Html {
+    let css = use_memo((), |_| custom_properties(get_theme()));
+    html! { <Global css={(*css).clone()} /> }
+}